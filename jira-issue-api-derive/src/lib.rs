@@ -0,0 +1,149 @@
+//! Derive macros for jira-issue-api, enabled with its `derive` feature.
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, LitStr};
+
+enum FieldKey {
+    /// Field id, e.g. summary or customfield_10016
+    Id(String),
+    /// Field name, e.g. Story Points, resolved through Issue.names
+    Name(String),
+}
+
+/// Struct field attributes
+struct FieldAttrs {
+    key: FieldKey,
+    /// Send null for None, clearing the field
+    clear: bool,
+}
+
+/// Implements `jira_issue_api::fields::JiraFields`.
+///
+/// Struct fields are mapped with `#[jira(field = "customfield_10016")]` or
+/// `#[jira(name = "Story Points")]`, unannotated fields use the struct field name as id.
+/// Fields serializing to null, e.g. None, are left out of edit bodies unless marked `#[jira(clear)]`.
+#[proc_macro_derive(JiraFields, attributes(jira))]
+pub fn derive_jira_fields(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn field_attrs(field: &syn::Field) -> Result<FieldAttrs, Error> {
+    let ident = field.ident.as_ref().expect("named field");
+    let mut key = None;
+    let mut clear = false;
+
+    for attr in field.attrs.iter().filter(|a| a.path().is_ident("jira")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("clear") {
+                clear = true;
+                return Ok(());
+            }
+            let value = meta.value()?.parse::<LitStr>()?.value();
+            if key.is_some() {
+                return Err(meta.error("only one of field or name can be set"));
+            }
+            if meta.path.is_ident("field") {
+                key = Some(FieldKey::Id(value));
+                Ok(())
+            } else if meta.path.is_ident("name") {
+                key = Some(FieldKey::Name(value));
+                Ok(())
+            } else {
+                Err(meta.error("expected field = \"...\", name = \"...\" or clear"))
+            }
+        })?;
+    }
+
+    Ok(FieldAttrs {
+        key: key.unwrap_or_else(|| FieldKey::Id(ident.to_string())),
+        clear,
+    })
+}
+
+fn key_tokens(key: &FieldKey, names: TokenStream2) -> TokenStream2 {
+    match key {
+        FieldKey::Id(id) => quote! { ::std::string::String::from(#id) },
+        FieldKey::Name(name) => {
+            quote! { ::jira_issue_api::fields::__private::field_id(#names, #name)? }
+        }
+    }
+}
+
+fn expand(input: DeriveInput) -> Result<TokenStream2, Error> {
+    let ident = &input.ident;
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    ident,
+                    "JiraFields requires a struct with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                ident,
+                "JiraFields can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut from_issue = Vec::new();
+    let mut to_edit = Vec::new();
+    for field in fields {
+        let field_ident = field.ident.as_ref().expect("named field");
+        let FieldAttrs { key, clear } = field_attrs(field)?;
+
+        let from_key = key_tokens(&key, quote! { __names });
+        from_issue.push(quote! {
+            #field_ident: ::jira_issue_api::fields::__private::get_field(&__fields, &#from_key)?
+        });
+
+        let to_key = key_tokens(&key, quote! { ::std::option::Option::Some(names) });
+        let insert = quote! { __map.insert(#to_key, __value); };
+        let insert = match clear {
+            true => insert,
+            false => quote! {
+                if !__value.is_null() {
+                    #insert
+                }
+            },
+        };
+        to_edit.push(quote! {
+            let __value = ::jira_issue_api::fields::__private::to_value(&self.#field_ident)?;
+            #insert
+        });
+    }
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::jira_issue_api::fields::JiraFields for #ident #ty_generics #where_clause {
+            fn from_issue(
+                issue: &::jira_issue_api::models::Issue,
+            ) -> ::std::result::Result<Self, ::jira_issue_api::JiraClientError> {
+                let __names = issue.names.as_ref();
+                let __fields = ::jira_issue_api::fields::__private::issue_fields(issue)?;
+                ::std::result::Result::Ok(Self {
+                    #(#from_issue,)*
+                })
+            }
+
+            fn to_edit_body_with_names(
+                &self,
+                names: &::std::collections::HashMap<::std::string::String, ::std::string::String>,
+            ) -> ::std::result::Result<
+                ::jira_issue_api::fields::__private::Value,
+                ::jira_issue_api::JiraClientError,
+            > {
+                let mut __map = ::jira_issue_api::fields::__private::Map::new();
+                #(#to_edit)*
+                ::std::result::Result::Ok(::jira_issue_api::fields::__private::edit_body(__map))
+            }
+        }
+    })
+}
@@ -0,0 +1,87 @@
+//! Typestates for JiraAPIClient, write methods are only callable on clients that may write.
+//!
+//! ```rust
+//! use jira_issue_api::access::{Authenticated, ReadOnly};
+//! use jira_issue_api::{Credential, JiraAPIClient, JiraClientConfig};
+//!
+//! let cfg = JiraClientConfig {
+//!     credential: Credential::PersonalAccessToken("xxxxxxx".to_string()),
+//!     url: "https://jira.example.com".to_string(),
+//!     ..Default::default()
+//! };
+//! // Fails upfront for Credential::Anonymous
+//! let client: JiraAPIClient = JiraAPIClient::new(&cfg).unwrap();
+//! // client.post_comment(&issue_key, body).await
+//!
+//! let read_only = client.read_only();
+//! // read_only.post_comment(&issue_key, body).await // Does not compile
+//!
+//! // Fails for Credential::Anonymous
+//! let authenticated: JiraAPIClient<_, Authenticated> = read_only.authenticated().unwrap();
+//! ```
+//!
+//! Anonymous clients are created with JiraAPIClient::anonymous, which only has read methods.
+//!
+//! ```rust,compile_fail
+//! use jira_issue_api::models::{IssueKey, PostCommentBody};
+//! use jira_issue_api::{JiraAPIClient, JiraClientConfig};
+//!
+//! async fn comment(key: IssueKey, body: PostCommentBody) {
+//!     let cfg = JiraClientConfig {
+//!         url: "https://jira.example.com".to_string(),
+//!         ..Default::default()
+//!     };
+//!     let client = JiraAPIClient::anonymous(&cfg).unwrap();
+//!     let _ = client.post_comment(&key, body).await;
+//! }
+//! ```
+//!
+//! ```rust,compile_fail
+//! use jira_issue_api::access::ReadOnly;
+//! use jira_issue_api::deployment::Configured;
+//! use jira_issue_api::models::{IssueKey, PostCommentBody};
+//! use jira_issue_api::JiraAPIClient;
+//!
+//! async fn comment(client: JiraAPIClient<Configured, ReadOnly>, key: IssueKey, body: PostCommentBody) {
+//!     let _ = client.post_comment(&key, body).await;
+//! }
+//! ```
+use std::fmt::Debug;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+pub trait Access: sealed::Sealed + Debug + Clone + Send + Sync + 'static {
+    /// Access of a copy with another credential, see JiraAPIClient::with_credential
+    type WithCredential: Access;
+    /// Only read methods, may use Credential::Anonymous
+    const READ_ONLY: bool;
+}
+
+/// Methods creating, updating or deleting data
+pub trait Writable: Access {}
+
+/// Credential is not Credential::Anonymous, the default
+#[derive(Debug, Clone, Copy)]
+pub struct Authenticated;
+
+/// Only read methods, e.g. for anonymous access
+#[derive(Debug, Clone, Copy)]
+pub struct ReadOnly;
+
+impl sealed::Sealed for Authenticated {}
+impl sealed::Sealed for ReadOnly {}
+
+/// with_credential rejects Credential::Anonymous
+impl Access for Authenticated {
+    type WithCredential = Authenticated;
+    const READ_ONLY: bool = false;
+}
+/// Read only clients stay read only
+impl Access for ReadOnly {
+    type WithCredential = ReadOnly;
+    const READ_ONLY: bool = true;
+}
+
+impl Writable for Authenticated {}
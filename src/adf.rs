@@ -0,0 +1,276 @@
+//! Atlassian Document Format, used for descriptions and comments by the Cloud v3 API
+//! https://developer.atlassian.com/cloud/jira/platform/apis/document/structure/
+//!
+//! ```rust
+//! use jira_issue_api::adf::{AdfDocument, AdfNode};
+//! use jira_issue_api::models::PostCommentBody;
+//!
+//! let doc = AdfDocument::new()
+//!     .paragraph(vec![
+//!         AdfNode::mention("5b10ac8d82e05b22cc7d4ef5", "@Jane"),
+//!         AdfNode::text(" the build failed, see "),
+//!         AdfNode::link("logs", "https://ci.example.com/1"),
+//!     ])
+//!     .code_block(Some("shell"), "cargo test");
+//!
+//! let body = PostCommentBody::new(doc);
+//! ```
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt::{Display, Error, Formatter};
+
+/// Plain text for the v2 API or an ADF document for the Cloud v3 API
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum RichText {
+    Plain(String),
+    Adf(AdfDocument),
+}
+
+impl From<String> for RichText {
+    fn from(value: String) -> Self {
+        RichText::Plain(value)
+    }
+}
+
+impl From<&str> for RichText {
+    fn from(value: &str) -> Self {
+        RichText::Plain(value.to_string())
+    }
+}
+
+impl From<AdfDocument> for RichText {
+    fn from(value: AdfDocument) -> Self {
+        RichText::Adf(value)
+    }
+}
+
+/// ADF documents are rendered as plain text
+impl Display for RichText {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match self {
+            RichText::Plain(text) => write!(f, "{}", text),
+            RichText::Adf(doc) => write!(f, "{}", doc.to_plain_text()),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename = "doc")]
+pub struct AdfDocument {
+    pub version: u32,
+    pub content: Vec<AdfNode>,
+}
+
+impl Default for AdfDocument {
+    fn default() -> Self {
+        AdfDocument {
+            version: 1,
+            content: Vec::new(),
+        }
+    }
+}
+
+impl AdfDocument {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// One paragraph per line
+    pub fn from_text(text: &str) -> Self {
+        text.lines().fold(Self::new(), |doc, line| {
+            let content = if line.is_empty() {
+                Vec::new()
+            } else {
+                vec![AdfNode::text(line)]
+            };
+            doc.paragraph(content)
+        })
+    }
+
+    pub fn paragraph(mut self, content: Vec<AdfNode>) -> Self {
+        self.content.push(AdfNode::Paragraph { content });
+        self
+    }
+
+    pub fn code_block(mut self, language: Option<&str>, code: &str) -> Self {
+        self.content.push(AdfNode::code_block(language, code));
+        self
+    }
+
+    pub fn to_plain_text(&self) -> String {
+        let mut text = String::new();
+        for (i, node) in self.content.iter().enumerate() {
+            if i > 0 {
+                text.push('\n');
+            }
+            node.push_plain_text(&mut text);
+        }
+        text
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum AdfNode {
+    Paragraph {
+        #[serde(default)]
+        content: Vec<AdfNode>,
+    },
+    Text {
+        text: String,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        marks: Vec<AdfMark>,
+    },
+    Mention {
+        attrs: MentionAttrs,
+    },
+    CodeBlock {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        attrs: Option<CodeBlockAttrs>,
+        #[serde(default)]
+        content: Vec<AdfNode>,
+    },
+    HardBreak,
+    /// Nodes without a typed variant, e.g. tables or panels, are kept as is
+    #[serde(untagged)]
+    Other(Value),
+}
+
+impl AdfNode {
+    pub fn text(text: &str) -> Self {
+        AdfNode::Text {
+            text: text.to_string(),
+            marks: Vec::new(),
+        }
+    }
+
+    pub fn marked_text(text: &str, marks: Vec<AdfMark>) -> Self {
+        AdfNode::Text {
+            text: text.to_string(),
+            marks,
+        }
+    }
+
+    pub fn link(text: &str, href: &str) -> Self {
+        Self::marked_text(
+            text,
+            vec![AdfMark::Link {
+                attrs: LinkAttrs {
+                    href: href.to_string(),
+                    title: None,
+                },
+            }],
+        )
+    }
+
+    /// id is the Cloud account id, text is displayed if the user can not be resolved
+    pub fn mention(id: &str, text: &str) -> Self {
+        AdfNode::Mention {
+            attrs: MentionAttrs {
+                id: id.to_string(),
+                text: Some(text.to_string()),
+            },
+        }
+    }
+
+    pub fn code_block(language: Option<&str>, code: &str) -> Self {
+        AdfNode::CodeBlock {
+            attrs: language.map(|language| CodeBlockAttrs {
+                language: Some(language.to_string()),
+            }),
+            content: vec![AdfNode::text(code)],
+        }
+    }
+
+    fn push_plain_text(&self, text: &mut String) {
+        match self {
+            AdfNode::Paragraph { content } | AdfNode::CodeBlock { content, .. } => {
+                content.iter().for_each(|node| node.push_plain_text(text))
+            }
+            AdfNode::Text { text: t, .. } => text.push_str(t),
+            AdfNode::Mention { attrs } => text.push_str(attrs.text.as_deref().unwrap_or(&attrs.id)),
+            AdfNode::HardBreak => text.push('\n'),
+            AdfNode::Other(_) => {}
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum AdfMark {
+    Strong,
+    Em,
+    Code,
+    Strike,
+    Underline,
+    Link {
+        attrs: LinkAttrs,
+    },
+    #[serde(untagged)]
+    Other(Value),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct LinkAttrs {
+    pub href: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct MentionAttrs {
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CodeBlockAttrs {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adf_round_trip_keeps_unknown_nodes() -> Result<(), serde_json::Error> {
+        let input = serde_json::json!({
+            "type": "doc",
+            "version": 1,
+            "content": [
+                {"type": "paragraph", "content": [
+                    {"type": "text", "text": "Hello ", "marks": [{"type": "strong"}]},
+                    {"type": "mention", "attrs": {"id": "5b10ac8d82e05b22cc7d4ef5", "text": "@Jane"}},
+                    {"type": "hardBreak"},
+                    {"type": "emoji", "attrs": {"shortName": ":tada:"}}
+                ]},
+                {"type": "codeBlock", "attrs": {"language": "rust"}, "content": [
+                    {"type": "text", "text": "fn main() {}"}
+                ]}
+            ]
+        });
+
+        let doc = serde_json::from_value::<AdfDocument>(input.clone())?;
+        assert_eq!(input, serde_json::to_value(&doc)?);
+        assert_eq!("Hello @Jane\n\nfn main() {}", doc.to_plain_text());
+        Ok(())
+    }
+
+    #[test]
+    fn rich_text_accepts_plain_and_adf() -> Result<(), serde_json::Error> {
+        assert_eq!(
+            RichText::from("text"),
+            serde_json::from_str::<RichText>(r#""text""#)?
+        );
+
+        let adf = serde_json::from_str::<RichText>(
+            r#"{"type": "doc", "version": 1, "content": [{"type": "paragraph", "content": [{"type": "text", "text": "text"}]}]}"#,
+        )?;
+        assert_eq!(RichText::from(AdfDocument::from_text("text")), adf);
+        assert_eq!("text", adf.to_string());
+        Ok(())
+    }
+}
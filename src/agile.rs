@@ -0,0 +1,620 @@
+//! Jira Software boards, sprints and epics
+//! https://developer.atlassian.com/cloud/jira/software/rest/intro/
+use crate::{
+    access::{Access, Writable},
+    client::deserialize_response,
+    deployment::Deployment,
+    models::{Issue, IssueKey},
+    JiraAPIClient, JiraClientError,
+};
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Error, Formatter};
+
+/// Issues moved per request, the Agile API rejects larger batches
+const MOVE_ISSUES_BATCH_SIZE: usize = 50;
+
+/// Agile API pages, total is not always known
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AgilePage<T> {
+    pub start_at: u32,
+    pub max_results: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<u32>,
+    #[serde(default)]
+    pub is_last: bool,
+    pub values: Vec<T>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum BoardType {
+    Scrum,
+    Kanban,
+    Simple,
+}
+
+impl Display for BoardType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let board_type = match self {
+            BoardType::Scrum => "scrum",
+            BoardType::Kanban => "kanban",
+            BoardType::Simple => "simple",
+        };
+        write!(f, "{}", board_type)
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Board {
+    pub id: u64,
+    #[serde(rename = "self")]
+    pub self_ref: String,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub board_type: BoardType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<BoardLocation>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardLocation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardConfiguration {
+    pub id: u64,
+    pub name: String,
+    #[serde(rename = "self")]
+    pub self_ref: String,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub board_type: Option<BoardType>,
+    pub filter: BoardFilterRef,
+    pub column_config: ColumnConfig,
+    /// Not set on kanban boards
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimation: Option<EstimationConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ranking: Option<RankingConfig>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct BoardFilterRef {
+    pub id: String,
+    #[serde(rename = "self")]
+    pub self_ref: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ColumnConfig {
+    pub columns: Vec<BoardColumn>,
+    /// e.g. none, issueCount or issueCountExclSubs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub constraint_type: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct BoardColumn {
+    pub name: String,
+    pub statuses: Vec<BoardColumnStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<u32>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct BoardColumnStatus {
+    pub id: String,
+    #[serde(rename = "self")]
+    pub self_ref: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct EstimationConfig {
+    /// e.g. field, or none
+    #[serde(rename = "type")]
+    pub estimation_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field: Option<EstimationField>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EstimationField {
+    /// e.g. customfield_10016 or timeoriginalestimate
+    pub field_id: String,
+    pub display_name: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RankingConfig {
+    pub rank_custom_field_id: u64,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SprintState {
+    Future,
+    Active,
+    Closed,
+}
+
+impl Display for SprintState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let state = match self {
+            SprintState::Future => "future",
+            SprintState::Active => "active",
+            SprintState::Closed => "closed",
+        };
+        write!(f, "{}", state)
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Sprint {
+    pub id: u64,
+    #[serde(rename = "self")]
+    pub self_ref: String,
+    pub state: SprintState,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub complete_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub origin_board_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub goal: Option<String>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PostSprintBody {
+    pub name: String,
+    pub origin_board_id: u64,
+    /// e.g. 2024-01-01T10:00:00.000+01:00
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub goal: Option<String>,
+}
+
+impl PostSprintBody {
+    pub fn new(origin_board_id: u64, name: impl Into<String>) -> Self {
+        PostSprintBody {
+            name: name.into(),
+            origin_board_id,
+            start_date: None,
+            end_date: None,
+            goal: None,
+        }
+    }
+}
+
+/// Fields left as None are unchanged.
+/// Start a future sprint with state Active and both dates, complete an active sprint with state Closed.
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PutSprintBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<SprintState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub goal: Option<String>,
+}
+
+impl PutSprintBody {
+    pub fn start(start_date: impl Into<String>, end_date: impl Into<String>) -> Self {
+        PutSprintBody {
+            state: Some(SprintState::Active),
+            start_date: Some(start_date.into()),
+            end_date: Some(end_date.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Unfinished issues stay in the sprint, move them with move_issues_to_sprint first
+    pub fn complete() -> Self {
+        PutSprintBody {
+            state: Some(SprintState::Closed),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Epic {
+    pub id: u64,
+    pub key: IssueKey,
+    #[serde(rename = "self")]
+    pub self_ref: String,
+    pub name: String,
+    pub summary: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<EpicColor>,
+    pub done: bool,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct EpicColor {
+    /// e.g. color_1
+    pub key: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AgileIssuePage {
+    pub start_at: u32,
+    pub max_results: u32,
+    pub total: u32,
+    pub issues: Vec<Issue>,
+}
+
+impl<D: Deployment, A: Access> JiraAPIClient<D, A> {
+    fn agile_url(&self, path: &str) -> Result<Url, JiraClientError> {
+        Ok(self.url.join(&format!("rest/agile/1.0/{}", path))?)
+    }
+
+    /// All boards visible to the user, filtered by project key or id, type and name contains
+    pub async fn get_boards(
+        &self,
+        project: Option<&str>,
+        board_type: Option<BoardType>,
+        name: Option<&str>,
+    ) -> Result<Vec<Board>, JiraClientError> {
+        let mut boards = Vec::new();
+        loop {
+            let url = self.agile_url("board")?;
+
+            let mut query = vec![
+                ("startAt", boards.len().to_string()),
+                ("maxResults", self.max_results.to_string()),
+            ];
+            if let Some(project) = project {
+                query.push(("projectKeyOrId", project.to_string()));
+            }
+            if let Some(board_type) = board_type {
+                query.push(("type", board_type.to_string()));
+            }
+            if let Some(name) = name {
+                query.push(("name", name.to_string()));
+            }
+
+            let response = self.send(self.client.get(url).query(&query)).await?;
+            let page = deserialize_response::<AgilePage<Board>>(response).await?;
+
+            let done = page.is_last || page.values.is_empty();
+            boards.extend(page.values);
+            if done {
+                return Ok(boards);
+            }
+        }
+    }
+
+    pub async fn get_board(&self, board_id: u64) -> Result<Board, JiraClientError> {
+        let url = self.agile_url(&format!("board/{}", board_id))?;
+
+        let response = self.send(self.client.get(url)).await?;
+        let body = deserialize_response::<Board>(response).await?;
+        Ok(body)
+    }
+
+    /// Sprints of a board in any of states, all sprints if states is empty
+    pub async fn get_sprints(
+        &self,
+        board_id: u64,
+        states: &[SprintState],
+    ) -> Result<Vec<Sprint>, JiraClientError> {
+        let state = states
+            .iter()
+            .map(SprintState::to_string)
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let mut sprints = Vec::new();
+        loop {
+            let url = self.agile_url(&format!("board/{}/sprint", board_id))?;
+
+            let mut query = vec![
+                ("startAt", sprints.len().to_string()),
+                ("maxResults", self.max_results.to_string()),
+            ];
+            if !state.is_empty() {
+                query.push(("state", state.clone()));
+            }
+
+            let response = self.send(self.client.get(url).query(&query)).await?;
+            let page = deserialize_response::<AgilePage<Sprint>>(response).await?;
+
+            let done = page.is_last || page.values.is_empty();
+            sprints.extend(page.values);
+            if done {
+                return Ok(sprints);
+            }
+        }
+    }
+
+    pub async fn get_sprint(&self, sprint_id: u64) -> Result<Sprint, JiraClientError> {
+        let url = self.agile_url(&format!("sprint/{}", sprint_id))?;
+
+        let response = self.send(self.client.get(url)).await?;
+        let body = deserialize_response::<Sprint>(response).await?;
+        Ok(body)
+    }
+
+    /// jql: further filters the sprint issues, e.g. statusCategory != Done
+    pub async fn get_sprint_issues(
+        &self,
+        sprint_id: u64,
+        jql: Option<&str>,
+        start_at: u32,
+    ) -> Result<AgileIssuePage, JiraClientError> {
+        let url = self.agile_url(&format!("sprint/{}/issue", sprint_id))?;
+
+        let mut query = vec![
+            ("startAt", start_at.to_string()),
+            ("maxResults", self.max_results.to_string()),
+        ];
+        if let Some(jql) = jql {
+            query.push(("jql", jql.to_string()));
+        }
+
+        let response = self.send(self.client.get(url).query(&query)).await?;
+        let body = deserialize_response::<AgileIssuePage>(response).await?;
+        Ok(body)
+    }
+
+    /// Epics of a board, done epics included
+    pub async fn get_epics(&self, board_id: u64) -> Result<Vec<Epic>, JiraClientError> {
+        let mut epics = Vec::new();
+        loop {
+            let url = self.agile_url(&format!("board/{}/epic", board_id))?;
+
+            let query = [
+                ("startAt", epics.len().to_string()),
+                ("maxResults", self.max_results.to_string()),
+            ];
+            let response = self.send(self.client.get(url).query(&query)).await?;
+            let page = deserialize_response::<AgilePage<Epic>>(response).await?;
+
+            let done = page.is_last || page.values.is_empty();
+            epics.extend(page.values);
+            if done {
+                return Ok(epics);
+            }
+        }
+    }
+
+    /// epic: id or key
+    pub async fn get_issues_for_epic(
+        &self,
+        epic: &str,
+        start_at: u32,
+    ) -> Result<AgileIssuePage, JiraClientError> {
+        self.get_agile_issues(&format!("epic/{}/issue", epic), start_at)
+            .await
+    }
+
+    /// Issues without an epic, limited to a board if board_id is set
+    pub async fn get_issues_without_epic(
+        &self,
+        board_id: Option<u64>,
+        start_at: u32,
+    ) -> Result<AgileIssuePage, JiraClientError> {
+        let path = match board_id {
+            Some(board_id) => format!("board/{}/epic/none/issue", board_id),
+            None => "epic/none/issue".to_string(),
+        };
+        self.get_agile_issues(&path, start_at).await
+    }
+
+    async fn get_agile_issues(
+        &self,
+        path: &str,
+        start_at: u32,
+    ) -> Result<AgileIssuePage, JiraClientError> {
+        let url = self.agile_url(path)?;
+
+        let query = [
+            ("startAt", start_at.to_string()),
+            ("maxResults", self.max_results.to_string()),
+        ];
+        let response = self.send(self.client.get(url).query(&query)).await?;
+        let body = deserialize_response::<AgileIssuePage>(response).await?;
+        Ok(body)
+    }
+
+    /// Columns with their statuses, estimation field and rank field of a board
+    pub async fn get_board_configuration(
+        &self,
+        board_id: u64,
+    ) -> Result<BoardConfiguration, JiraClientError> {
+        let url = self.agile_url(&format!("board/{}/configuration", board_id))?;
+
+        let response = self.send(self.client.get(url)).await?;
+        let body = deserialize_response::<BoardConfiguration>(response).await?;
+        Ok(body)
+    }
+}
+
+impl<D: Deployment, A: Writable> JiraAPIClient<D, A> {
+    pub async fn create_sprint(&self, body: &PostSprintBody) -> Result<Sprint, JiraClientError> {
+        let url = self.agile_url("sprint")?;
+
+        let response = self.send(self.client.post(url).json(body)).await?;
+        let body = deserialize_response::<Sprint>(response).await?;
+        Ok(body)
+    }
+
+    /// Partial update, see PutSprintBody::start and PutSprintBody::complete
+    pub async fn update_sprint(
+        &self,
+        sprint_id: u64,
+        body: &PutSprintBody,
+    ) -> Result<Sprint, JiraClientError> {
+        let url = self.agile_url(&format!("sprint/{}", sprint_id))?;
+
+        let response = self.send(self.client.post(url).json(body)).await?;
+        let body = deserialize_response::<Sprint>(response).await?;
+        Ok(body)
+    }
+
+    /// Move issues to an open sprint, in batches of 50.
+    /// Batches before a failed one stay moved.
+    pub async fn move_issues_to_sprint(
+        &self,
+        sprint_id: u64,
+        issue_keys: &[IssueKey],
+    ) -> Result<(), JiraClientError> {
+        self.move_issues(&format!("sprint/{}/issue", sprint_id), issue_keys)
+            .await
+    }
+
+    /// Remove issues from any future or active sprint, in batches of 50.
+    /// Batches before a failed one stay moved.
+    pub async fn move_issues_to_backlog(
+        &self,
+        issue_keys: &[IssueKey],
+    ) -> Result<(), JiraClientError> {
+        self.move_issues("backlog/issue", issue_keys).await
+    }
+
+    /// epic: id or key, None removes the issues from their epic. Moved in batches of 50,
+    /// batches before a failed one stay moved.
+    pub async fn move_issues_to_epic(
+        &self,
+        epic: Option<&str>,
+        issue_keys: &[IssueKey],
+    ) -> Result<(), JiraClientError> {
+        let path = format!("epic/{}/issue", epic.unwrap_or("none"));
+        self.move_issues(&path, issue_keys).await
+    }
+
+    async fn move_issues(
+        &self,
+        path: &str,
+        issue_keys: &[IssueKey],
+    ) -> Result<(), JiraClientError> {
+        for body in move_issues_bodies(issue_keys) {
+            let url = self.agile_url(path)?;
+
+            self.send(self.client.post(url).json(&body)).await?;
+        }
+        Ok(())
+    }
+
+    /// Only future sprints can be deleted
+    pub async fn delete_sprint(&self, sprint_id: u64) -> Result<(), JiraClientError> {
+        let url = self.agile_url(&format!("sprint/{}", sprint_id))?;
+
+        self.send(self.client.delete(url)).await?;
+        Ok(())
+    }
+}
+
+/// {"issues": [...]} body per batch of MOVE_ISSUES_BATCH_SIZE
+fn move_issues_bodies(issue_keys: &[IssueKey]) -> impl Iterator<Item = serde_json::Value> + '_ {
+    issue_keys
+        .chunks(MOVE_ISSUES_BATCH_SIZE)
+        .map(|batch| serde_json::json!({ "issues": batch }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn move_issues_in_batches() -> Result<(), JiraClientError> {
+        let issue_keys = (1..=120)
+            .map(|i| IssueKey::try_from(format!("JB-{}", i)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let bodies = move_issues_bodies(&issue_keys).collect::<Vec<_>>();
+        assert_eq!(
+            vec![50, 50, 20],
+            bodies
+                .iter()
+                .map(|body| body["issues"].as_array().map_or(0, Vec::len))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!("JB-51", bodies[1]["issues"][0]);
+        assert_eq!("JB-120", bodies[2]["issues"][19]);
+        Ok(())
+    }
+
+    #[test]
+    fn board_configuration_deserialize() -> Result<(), serde_json::Error> {
+        let config = serde_json::from_str::<BoardConfiguration>(
+            r#"{
+                "id": 1,
+                "name": "JB board",
+                "self": "https://jira.example.com/rest/agile/1.0/board/1/configuration",
+                "type": "scrum",
+                "location": {"type": "project", "key": "JB", "id": "10000"},
+                "filter": {"id": "10000", "self": "https://jira.example.com/rest/api/2/filter/10000"},
+                "columnConfig": {
+                    "columns": [
+                        {"name": "To Do", "statuses": [{"id": "1", "self": "https://jira.example.com/rest/api/2/status/1"}]},
+                        {"name": "Done", "statuses": [{"id": "10001", "self": "https://jira.example.com/rest/api/2/status/10001"}], "max": 5}
+                    ],
+                    "constraintType": "issueCount"
+                },
+                "estimation": {"type": "field", "field": {"fieldId": "customfield_10016", "displayName": "Story Points"}},
+                "ranking": {"rankCustomFieldId": 10019}
+            }"#,
+        )?;
+
+        assert_eq!(Some(BoardType::Scrum), config.board_type);
+        assert_eq!(2, config.column_config.columns.len());
+        assert_eq!(Some(5), config.column_config.columns[1].max);
+        assert_eq!(
+            "customfield_10016",
+            config.estimation.and_then(|e| e.field).unwrap().field_id
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn put_sprint_body_start_and_complete() -> Result<(), serde_json::Error> {
+        assert_eq!(
+            serde_json::json!({
+                "state": "active",
+                "startDate": "2024-01-01T10:00:00.000+01:00",
+                "endDate": "2024-01-15T10:00:00.000+01:00",
+            }),
+            serde_json::to_value(PutSprintBody::start(
+                "2024-01-01T10:00:00.000+01:00",
+                "2024-01-15T10:00:00.000+01:00"
+            ))?
+        );
+        assert_eq!(
+            serde_json::json!({"state": "closed"}),
+            serde_json::to_value(PutSprintBody::complete())?
+        );
+        Ok(())
+    }
+}
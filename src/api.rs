@@ -0,0 +1,488 @@
+//! Traits of the client methods, so code using the client can be tested against a fake.
+//!
+//! The traits follow the typestates of JiraAPIClient, e.g. write methods are in JiraApiWrite.
+//! Every method has a default implementation failing with JiraClientError::UnknownError,
+//! so fakes only implement the methods under test, see [mock](crate::mock) with the `mock` feature.
+//! Methods are `Send`, so the traits are not available on wasm.
+//!
+//! ```rust
+//! use jira_issue_api::api::JiraApi;
+//! use jira_issue_api::models::IssueKey;
+//! use jira_issue_api::JiraClientError;
+//!
+//! async fn summary(jira: &impl JiraApi, key: &IssueKey) -> Result<Option<String>, JiraClientError> {
+//!     Ok(jira.get_issue(key, None).await?.fields.summary)
+//! }
+//! ```
+use crate::{
+    access::{Access, Writable},
+    agile::*,
+    assets::*,
+    deployment::{CloudDeployment, Deployment, DeploymentType},
+    fields::FieldRegistry,
+    jql::{JqlValidation, SortOrder},
+    models::*,
+    session::SessionLogin,
+    webhooks::{RegisteredWebhook, Webhook, WebhookDetails},
+    AttachmentUpload, JiraAPIClient, JiraClientError, Thumbnail,
+};
+use futures_util::{stream::BoxStream, StreamExt};
+use serde_json::Value;
+use std::{collections::BTreeMap, future::Future, sync::Arc};
+
+/// Result of methods not implemented by a fake
+trait Unsupported {
+    fn unsupported(method: &str) -> Self;
+}
+
+impl<T> Unsupported for Result<T, JiraClientError> {
+    fn unsupported(method: &str) -> Self {
+        Err(JiraClientError::UnknownError(format!(
+            "{} is not implemented",
+            method
+        )))
+    }
+}
+
+impl<T> Unsupported for Vec<Result<T, JiraClientError>> {
+    fn unsupported(method: &str) -> Self {
+        vec![Result::unsupported(method)]
+    }
+}
+
+/// Calls the JiraAPIClient method of the same name, unless another body is given
+macro_rules! forward {
+    ($self:ident, $name:ident($($arg:ident),*)) => {
+        JiraAPIClient::$name($self, $($arg),*)
+    };
+    ($self:ident, $name:ident($($arg:ident),*) => |$client:ident| $body:expr) => {{
+        let $client = $self;
+        $body
+    }};
+}
+
+/// Declare a trait of client methods and implement it for JiraAPIClient
+macro_rules! api {
+    (
+        $(#[$meta:meta])*
+        pub trait $trait:ident $(: $super:ident $(+ $supers:ident)*)? for <D: $deployment:ident, A: $access:ident> {
+            $($name:ident($($arg:ident: $ty:ty),* $(,)?) -> $ret:ty $(=> |$client:ident| $body:expr)?;)*
+        }
+    ) => {
+        $(#[$meta])*
+        pub trait $trait $(: $super $(+ $supers)*)? {
+            $(
+                #[doc = concat!("See [JiraAPIClient::", stringify!($name), "]")]
+                fn $name(&self, $($arg: $ty),*) -> impl Future<Output = $ret> + Send {
+                    let _ = ($($arg,)*);
+                    async { Unsupported::unsupported(stringify!($name)) }
+                }
+            )*
+        }
+
+        impl<D: $deployment, A: $access> $trait for JiraAPIClient<D, A> {
+            $(
+                fn $name(&self, $($arg: $ty),*) -> impl Future<Output = $ret> + Send {
+                    forward!(self, $name($($arg),*) $(=> |$client| $body)?)
+                }
+            )*
+        }
+    };
+}
+
+api! {
+    /// Read methods of every deployment
+    pub trait JiraApi for <D: Deployment, A: Access> {
+        query_issues(
+            query: &str,
+            fields: Option<Vec<String>>,
+            expand_options: Option<Vec<String>>,
+        ) -> Result<PostIssueQueryResponseBody, JiraClientError>;
+        query_issues_all(
+            query: &str,
+            fields: Option<Vec<String>>,
+            expand_options: Option<Vec<String>>,
+        ) -> Result<Vec<Issue>, JiraClientError>;
+        query_issues_stream(
+            query: &str,
+            fields: Option<Vec<String>>,
+            expand_options: Option<Vec<String>>,
+        ) -> Result<BoxStream<'_, Result<Issue, JiraClientError>>, JiraClientError> => |client| async move {
+            let stream = JiraAPIClient::query_issues_stream(client, query, fields, expand_options);
+            Ok(stream.await?.boxed())
+        };
+        query_filter_issues(
+            filter_id: &str,
+            fields: Option<Vec<String>>,
+            expand_options: Option<Vec<String>>,
+        ) -> Result<BoxStream<'_, Result<Issue, JiraClientError>>, JiraClientError> => |client| async move {
+            let stream = JiraAPIClient::query_filter_issues(client, filter_id, fields, expand_options);
+            Ok(stream.await?.boxed())
+        };
+        validate_jql(queries: &[&str]) -> Result<Vec<JqlValidation>, JiraClientError>;
+        get_issue(
+            issue_key: &IssueKey,
+            expand_options: Option<&str>,
+        ) -> Result<Issue, JiraClientError>;
+        get_issues(
+            issue_keys: &[IssueKey],
+            expand_options: Option<&str>,
+        ) -> Vec<Result<Issue, JiraClientError>>;
+        get_comments(
+            issue_key: &IssueKey,
+            start_at: u32,
+            max_results: Option<u32>,
+            order_by: Option<SortOrder>,
+        ) -> Result<CommentPage, JiraClientError>;
+        get_worklogs(
+            issue_key: &IssueKey,
+            start_at: u32,
+            max_results: Option<u32>,
+        ) -> Result<WorklogPage, JiraClientError>;
+        get_all_worklogs(
+            issue_key: &IssueKey,
+            start_at: u32,
+        ) -> Result<Vec<Worklog>, JiraClientError>;
+        get_worklogs_updated(since: i64) -> Result<WorklogChangePage, JiraClientError>;
+        get_worklogs_deleted(since: i64) -> Result<WorklogChangePage, JiraClientError>;
+        get_worklogs_stream(
+            issue_key: &IssueKey,
+            start_at: u32,
+        ) -> Result<BoxStream<'_, Result<Worklog, JiraClientError>>, JiraClientError> => |client| async move {
+            let stream = JiraAPIClient::get_worklogs_stream(client, issue_key, start_at);
+            Ok(stream.await?.boxed())
+        };
+        get_worklogs_by_ids(ids: &[u64]) -> Result<Vec<Worklog>, JiraClientError>;
+        get_worklogs_by_ids_stream(
+            ids: &[u64],
+        ) -> Result<BoxStream<'_, Result<Worklog, JiraClientError>>, JiraClientError> => |client| async move {
+            let stream = JiraAPIClient::get_worklogs_by_ids_stream(client, ids);
+            Ok(stream.await?.boxed())
+        };
+        get_audit_records(params: &GetAuditRecordsParams) -> Result<AuditRecordPage, JiraClientError>;
+        get_audit_records_stream(
+            params: &GetAuditRecordsParams,
+        ) -> Result<BoxStream<'_, Result<AuditRecord, JiraClientError>>, JiraClientError> => |client| async move {
+            let stream = JiraAPIClient::get_audit_records_stream(client, params);
+            Ok(stream.await?.boxed())
+        };
+        get_transitions(
+            issue_key: &IssueKey,
+            expand_options: Option<&str>,
+        ) -> Result<GetTransitionsBody, JiraClientError>;
+        get_assignable_users(
+            params: &GetAssignableUserParams,
+        ) -> Result<Vec<User>, JiraClientError>;
+        get_all_assignable_users(
+            params: &GetAssignableUserParams,
+        ) -> Result<Vec<User>, JiraClientError>;
+        get_assignable_users_multi_project(
+            projects: &[&str],
+            query: Option<&str>,
+        ) -> Result<Vec<User>, JiraClientError>;
+        get_viewable_users(params: &GetViewableUserParams) -> Result<Vec<User>, JiraClientError>;
+        find_users_for_picker(
+            query: &str,
+            max_results: Option<u32>,
+            exclude: &[&str],
+        ) -> Result<GetUserPickerResponseBody, JiraClientError>;
+        search_users(
+            query: &str,
+            start_at: u32,
+            max_results: Option<u32>,
+        ) -> Result<Vec<User>, JiraClientError>;
+        get_groups(
+            query: Option<&str>,
+            max_results: Option<u32>,
+        ) -> Result<GetGroupsResponseBody, JiraClientError>;
+        get_group_members(
+            group: &str,
+            start_at: u32,
+            max_results: Option<u32>,
+        ) -> Result<GroupMemberPage, JiraClientError>;
+        get_server_info() -> Result<ServerInfo, JiraClientError>;
+        detect_deployment() -> Result<DeploymentType, JiraClientError>;
+        get_myself() -> Result<User, JiraClientError>;
+        get_user(user: &str) -> Result<User, JiraClientError>;
+        get_create_meta_issue_types(
+            project: &str,
+        ) -> Result<Vec<CreateMetaIssueType>, JiraClientError>;
+        get_create_meta(
+            project: &str,
+            issuetype_id: &str,
+        ) -> Result<Vec<FieldMetadata>, JiraClientError>;
+        get_edit_meta(
+            issue_key: &IssueKey,
+        ) -> Result<BTreeMap<String, FieldMetadata>, JiraClientError>;
+        get_issue_types() -> Result<Vec<IssueType>, JiraClientError>;
+        get_priorities() -> Result<Vec<Priority>, JiraClientError>;
+        get_statuses() -> Result<Vec<Status>, JiraClientError>;
+        get_status_categories() -> Result<Vec<StatusCategoryDetails>, JiraClientError>;
+        suggest_labels(query: &str) -> Result<Vec<String>, JiraClientError>;
+        get_resolutions() -> Result<Vec<Resolution>, JiraClientError>;
+        get_fields() -> Result<Vec<Field>, JiraClientError>;
+        field_registry() -> Result<Arc<FieldRegistry>, JiraClientError>;
+        get_filter(id: &str) -> Result<Filter, JiraClientError>;
+        get_filter_share_permissions(id: &str) -> Result<Vec<SharePermission>, JiraClientError>;
+        get_filter_columns(id: &str) -> Result<Vec<ColumnItem>, JiraClientError>;
+        get_dashboards(start_at: u32) -> Result<DashboardPage, JiraClientError>;
+        get_dashboard(id: &str) -> Result<Dashboard, JiraClientError>;
+        search_filters(
+            filter: Option<&str>,
+        ) -> Result<GetFilterSearchResponseBody, JiraClientError>;
+        get_favourite_filters() -> Result<Vec<Filter>, JiraClientError>;
+        get_security_levels(project_key: &str) -> Result<Vec<SecurityLevel>, JiraClientError>;
+        resolve_security_level(
+            project_key: &str,
+            name: &str,
+        ) -> Result<SecurityLevelRef, JiraClientError>;
+        get_issue_link_types() -> Result<Vec<IssueLinkType>, JiraClientError>;
+        get_remote_links(issue_key: &IssueKey) -> Result<Vec<RemoteLink>, JiraClientError>;
+        get_watchers(issue_key: &IssueKey) -> Result<GetWatchersResponseBody, JiraClientError>;
+        get_votes(issue_key: &IssueKey) -> Result<Votes, JiraClientError>;
+        get_projects() -> Result<Vec<Project>, JiraClientError>;
+        get_project(
+            key_or_id: &str,
+            expand_options: Option<&str>,
+        ) -> Result<Project, JiraClientError>;
+        get_project_statuses(
+            project_key_or_id: &str,
+        ) -> Result<Vec<ProjectIssueTypeStatuses>, JiraClientError>;
+        get_project_roles(
+            project_key_or_id: &str,
+        ) -> Result<BTreeMap<String, String>, JiraClientError>;
+        get_role_actors(
+            project_key_or_id: &str,
+            role_id: u64,
+        ) -> Result<ProjectRole, JiraClientError>;
+        get_component(id: &str) -> Result<Component, JiraClientError>;
+        get_component_related_issue_count(id: &str) -> Result<u64, JiraClientError>;
+        get_issue_property_keys(
+            issue_key: &IssueKey,
+        ) -> Result<Vec<EntityPropertyKey>, JiraClientError>;
+        get_issue_property(
+            issue_key: &IssueKey,
+            property_key: &str,
+        ) -> Result<EntityProperty, JiraClientError>;
+        get_user_property_keys(user: &str) -> Result<Vec<EntityPropertyKey>, JiraClientError>;
+        get_user_property(
+            user: &str,
+            property_key: &str,
+        ) -> Result<EntityProperty, JiraClientError>;
+        get_attachment_meta(id: &str) -> Result<Attachment, JiraClientError>;
+        get_attachment_thumbnail(id: &str) -> Result<Option<Thumbnail>, JiraClientError>;
+        get_project_versions(project_key_or_id: &str) -> Result<Vec<Version>, JiraClientError>;
+        get_version(id: &str) -> Result<Version, JiraClientError>;
+        get_boards(
+            project: Option<&str>,
+            board_type: Option<BoardType>,
+            name: Option<&str>,
+        ) -> Result<Vec<Board>, JiraClientError>;
+        get_board(board_id: u64) -> Result<Board, JiraClientError>;
+        get_sprints(board_id: u64, states: &[SprintState]) -> Result<Vec<Sprint>, JiraClientError>;
+        get_sprint(sprint_id: u64) -> Result<Sprint, JiraClientError>;
+        get_sprint_issues(
+            sprint_id: u64,
+            jql: Option<&str>,
+            start_at: u32,
+        ) -> Result<AgileIssuePage, JiraClientError>;
+        get_epics(board_id: u64) -> Result<Vec<Epic>, JiraClientError>;
+        get_issues_for_epic(epic: &str, start_at: u32) -> Result<AgileIssuePage, JiraClientError>;
+        get_issues_without_epic(
+            board_id: Option<u64>,
+            start_at: u32,
+        ) -> Result<AgileIssuePage, JiraClientError>;
+        get_board_configuration(board_id: u64) -> Result<BoardConfiguration, JiraClientError>;
+        get_object_schemas() -> Result<GetObjectSchemaListResponseBody, JiraClientError>;
+        query_assets(
+            iql: &str,
+            page: u32,
+            include_attributes: bool,
+        ) -> Result<GetIqlObjectsResponseBody, JiraClientError>;
+        get_object_attributes(object_id: u32) -> Result<Vec<ObjectAttribute>, JiraClientError>;
+        generate_fields_struct(
+            struct_name: &str,
+            field_ids: &[&str],
+        ) -> Result<String, JiraClientError>;
+        generate_create_meta_struct(
+            struct_name: &str,
+            project: &str,
+            issuetype_id: &str,
+        ) -> Result<String, JiraClientError>;
+        login() -> Result<SessionLogin, JiraClientError>;
+        logout() -> Result<(), JiraClientError>;
+        list_webhooks() -> Result<Vec<Webhook>, JiraClientError>;
+    }
+}
+
+api! {
+    /// Read methods only available on Jira Cloud
+    pub trait JiraApiCloud: JiraApi for <D: CloudDeployment, A: Access> {
+        get_my_filters(include_favourites: bool) -> Result<Vec<Filter>, JiraClientError>;
+        get_dashboard_gadgets(dashboard_id: &str) -> Result<Vec<DashboardGadget>, JiraClientError>;
+        search_jql(
+            body: &PostEnhancedSearchBody,
+        ) -> Result<EnhancedSearchResponseBody, JiraClientError>;
+        search_jql_all(body: &PostEnhancedSearchBody) -> Result<Vec<Issue>, JiraClientError>;
+        get_labels() -> Result<Vec<String>, JiraClientError>;
+        get_changelog(
+            issue_key: &IssueKey,
+            start_at: u32,
+            max_results: Option<u32>,
+        ) -> Result<GetChangelogResponseBody, JiraClientError>;
+        get_failed_webhooks(
+            after: Option<i64>,
+        ) -> Result<GetFailedWebhooksResponseBody, JiraClientError>;
+        get_all_failed_webhooks(after: Option<i64>) -> Result<Vec<FailedWebhook>, JiraClientError>;
+    }
+}
+
+api! {
+    /// Methods creating, updating or deleting data
+    pub trait JiraApiWrite: JiraApi for <D: Deployment, A: Writable> {
+        create_issue(body: &PostIssueBody) -> Result<CreatedIssueResponse, JiraClientError>;
+        edit_issue(
+            issue_key: &IssueKey,
+            body: &EditIssueBody,
+            notify_users: bool,
+        ) -> Result<(), JiraClientError>;
+        post_worklog(
+            issue_key: &IssueKey,
+            body: PostWorklogBody,
+            adjust_estimate: &AdjustEstimate,
+        ) -> Result<Worklog, JiraClientError>;
+        update_worklog(
+            issue_key: &IssueKey,
+            worklog_id: &str,
+            body: PostWorklogBody,
+        ) -> Result<Worklog, JiraClientError>;
+        delete_worklog(
+            issue_key: &IssueKey,
+            worklog_id: &str,
+            adjust_estimate: &AdjustEstimate,
+        ) -> Result<(), JiraClientError>;
+        post_comment(
+            issue_key: &IssueKey,
+            body: PostCommentBody,
+        ) -> Result<Comment, JiraClientError>;
+        update_comment(
+            issue_key: &IssueKey,
+            comment_id: &str,
+            body: PostCommentBody,
+        ) -> Result<Comment, JiraClientError>;
+        delete_comment(issue_key: &IssueKey, comment_id: &str) -> Result<(), JiraClientError>;
+        post_transition(
+            issue_key: &IssueKey,
+            transition: &PostTransitionBody,
+        ) -> Result<(), JiraClientError>;
+        post_assign_user(issue_key: &IssueKey, user: &User) -> Result<(), JiraClientError>;
+        upload_attachment(
+            issue_key: &IssueKey,
+            upload: AttachmentUpload,
+            progress: impl Fn(u64, Option<u64>) + Send + Sync + 'static,
+        ) -> Result<Vec<Attachment>, JiraClientError>;
+        upload_attachments(
+            issue_key: &IssueKey,
+            uploads: Vec<AttachmentUpload>,
+            concurrency: usize,
+            progress: impl Fn(&str, u64, Option<u64>) + Send + Sync + 'static,
+        ) -> Vec<Result<Vec<Attachment>, JiraClientError>>;
+        assign_issue(issue_key: &IssueKey, assignee: &AssigneeRef) -> Result<(), JiraClientError>;
+        create_issue_link(
+            inward: &IssueKey,
+            outward: &IssueKey,
+            link_type: &str,
+            comment: Option<PostCommentBody>,
+        ) -> Result<(), JiraClientError>;
+        delete_issue_link(id: &str) -> Result<(), JiraClientError>;
+        create_remote_link(
+            issue_key: &IssueKey,
+            body: &PostRemoteLinkBody,
+        ) -> Result<PostRemoteLinkResponseBody, JiraClientError>;
+        delete_remote_link(issue_key: &IssueKey, id: u64) -> Result<(), JiraClientError>;
+        delete_remote_link_by_global_id(
+            issue_key: &IssueKey,
+            global_id: &str,
+        ) -> Result<(), JiraClientError>;
+        add_watcher(issue_key: &IssueKey, user: &str) -> Result<(), JiraClientError>;
+        remove_watcher(issue_key: &IssueKey, user: &str) -> Result<(), JiraClientError>;
+        add_vote(issue_key: &IssueKey) -> Result<(), JiraClientError>;
+        remove_vote(issue_key: &IssueKey) -> Result<(), JiraClientError>;
+        add_user_to_group(group: &str, user: &str) -> Result<(), JiraClientError>;
+        remove_user_from_group(group: &str, user: &str) -> Result<(), JiraClientError>;
+        set_issue_property(
+            issue_key: &IssueKey,
+            property_key: &str,
+            value: &Value,
+        ) -> Result<(), JiraClientError>;
+        delete_issue_property(
+            issue_key: &IssueKey,
+            property_key: &str,
+        ) -> Result<(), JiraClientError>;
+        set_user_property(
+            user: &str,
+            property_key: &str,
+            value: &Value,
+        ) -> Result<(), JiraClientError>;
+        delete_user_property(user: &str, property_key: &str) -> Result<(), JiraClientError>;
+        add_role_actors(
+            project_key_or_id: &str,
+            role_id: u64,
+            body: &PostRoleActorsBody,
+        ) -> Result<ProjectRole, JiraClientError>;
+        remove_role_actor(
+            project_key_or_id: &str,
+            role_id: u64,
+            actor: &RoleActorRef,
+        ) -> Result<(), JiraClientError>;
+        create_component(body: &PostComponentBody) -> Result<Component, JiraClientError>;
+        update_component(id: &str, body: &PutComponentBody) -> Result<Component, JiraClientError>;
+        delete_component(id: &str, move_issues_to: Option<&str>) -> Result<(), JiraClientError>;
+        create_filter(body: &PostFilterBody) -> Result<Filter, JiraClientError>;
+        update_filter(id: &str, body: &PostFilterBody) -> Result<Filter, JiraClientError>;
+        delete_filter(id: &str) -> Result<(), JiraClientError>;
+        set_filter_favourite(id: &str, favourite: bool) -> Result<Filter, JiraClientError>;
+        set_filter_columns(id: &str, fields: &[&str]) -> Result<(), JiraClientError>;
+        add_filter_share_permission(
+            id: &str,
+            body: &PostSharePermissionBody,
+        ) -> Result<Vec<SharePermission>, JiraClientError>;
+        delete_filter_share_permission(id: &str, permission_id: u64) -> Result<(), JiraClientError>;
+        delete_attachment(id: &str) -> Result<(), JiraClientError>;
+        create_version(body: &PostVersionBody) -> Result<Version, JiraClientError>;
+        update_version(id: &str, body: &PutVersionBody) -> Result<Version, JiraClientError>;
+        archive_version(id: &str) -> Result<Version, JiraClientError>;
+        unarchive_version(id: &str) -> Result<Version, JiraClientError>;
+        delete_version(
+            id: &str,
+            move_fixed_to: Option<&str>,
+            move_affected_to: Option<&str>,
+        ) -> Result<(), JiraClientError>;
+        move_version(id: &str, body: &PostVersionMoveBody) -> Result<Version, JiraClientError>;
+        merge_version(id: &str, into_id: &str) -> Result<(), JiraClientError>;
+        create_sprint(body: &PostSprintBody) -> Result<Sprint, JiraClientError>;
+        update_sprint(sprint_id: u64, body: &PutSprintBody) -> Result<Sprint, JiraClientError>;
+        move_issues_to_sprint(
+            sprint_id: u64,
+            issue_keys: &[IssueKey],
+        ) -> Result<(), JiraClientError>;
+        move_issues_to_backlog(issue_keys: &[IssueKey]) -> Result<(), JiraClientError>;
+        move_issues_to_epic(
+            epic: Option<&str>,
+            issue_keys: &[IssueKey],
+        ) -> Result<(), JiraClientError>;
+        delete_sprint(sprint_id: u64) -> Result<(), JiraClientError>;
+        register_webhooks(
+            url: &str,
+            webhooks: &[WebhookDetails],
+        ) -> Result<Vec<RegisteredWebhook>, JiraClientError>;
+        delete_webhooks(ids: &[i64]) -> Result<(), JiraClientError>;
+    }
+}
+
+api! {
+    /// Write methods only available on Jira Cloud
+    pub trait JiraApiCloudWrite: JiraApiCloud + JiraApiWrite for <D: CloudDeployment, A: Writable> {
+        refresh_webhooks(ids: &[i64]) -> Result<PutWebhookRefreshResponseBody, JiraClientError>;
+    }
+}
@@ -0,0 +1,135 @@
+//! JSM Assets (formerly Insight) for Data Center
+//! https://docs.atlassian.com/assets/REST/10.0.0/
+use crate::{
+    access::Access, client::deserialize_response, deployment::Deployment, JiraAPIClient,
+    JiraClientError,
+};
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetObjectSchemaListResponseBody {
+    #[serde(rename = "objectschemas")]
+    pub object_schemas: Vec<ObjectSchema>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectSchema {
+    pub id: u32,
+    pub name: String,
+    pub object_schema_key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub object_count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub object_type_count: Option<u32>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetIqlObjectsResponseBody {
+    pub object_entries: Vec<AssetObject>,
+    pub total_filter_count: u32,
+    /// Pages start at 1
+    pub page_number: u32,
+    pub page_size: u32,
+    pub page_object_size: u32,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetObject {
+    pub id: u32,
+    pub label: String,
+    pub object_key: String,
+    pub object_type: AssetObjectType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated: Option<String>,
+    /// Empty unless attributes are included in the query
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attributes: Vec<ObjectAttribute>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetObjectType {
+    pub id: u32,
+    pub name: String,
+    pub object_schema_id: u32,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectAttribute {
+    pub id: u32,
+    pub object_type_attribute_id: u32,
+    pub object_attribute_values: Vec<ObjectAttributeValue>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectAttributeValue {
+    /// Type depends on the attribute, e.g. text, number, or a referenced object
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_value: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_value: Option<String>,
+}
+
+impl<D: Deployment, A: Access> JiraAPIClient<D, A> {
+    fn assets_url(&self, path: &str) -> Result<Url, JiraClientError> {
+        Ok(self.url.join(&format!("rest/insight/1.0/{}", path))?)
+    }
+
+    pub async fn get_object_schemas(
+        &self,
+    ) -> Result<GetObjectSchemaListResponseBody, JiraClientError> {
+        let url = self.assets_url("objectschema/list")?;
+
+        let response = self.send(self.client.get(url)).await?;
+        let body = deserialize_response::<GetObjectSchemaListResponseBody>(response).await?;
+        Ok(body)
+    }
+
+    /// page: starts at 1
+    pub async fn query_assets(
+        &self,
+        iql: &str,
+        page: u32,
+        include_attributes: bool,
+    ) -> Result<GetIqlObjectsResponseBody, JiraClientError> {
+        let url = self.assets_url("iql/objects")?;
+
+        let query = [
+            ("iql", iql.to_string()),
+            ("page", page.to_string()),
+            ("resultPerPage", self.max_results.to_string()),
+            ("includeAttributes", include_attributes.to_string()),
+        ];
+
+        let response = self.send(self.client.get(url).query(&query)).await?;
+        let body = deserialize_response::<GetIqlObjectsResponseBody>(response).await?;
+        Ok(body)
+    }
+
+    pub async fn get_object_attributes(
+        &self,
+        object_id: u32,
+    ) -> Result<Vec<ObjectAttribute>, JiraClientError> {
+        let url = self.assets_url(&format!("object/{}/attributes", object_id))?;
+
+        let response = self.send(self.client.get(url)).await?;
+        let body = deserialize_response::<Vec<ObjectAttribute>>(response).await?;
+        Ok(body)
+    }
+}
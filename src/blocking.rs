@@ -0,0 +1,624 @@
+//! Blocking client, enabled with the `blocking` feature,
+//! for scripts and build tooling without an async runtime.
+//!
+//! Requests are driven by an internal current thread tokio runtime shared by clones of the client,
+//! so it must not be used from within an async runtime.
+//!
+//! ```rust,no_run
+//! # fn blocking() -> Result<(), jira_issue_api::JiraClientError> {
+//! use jira_issue_api::blocking::JiraAPIBlockingClient;
+//! use jira_issue_api::models::IssueKey;
+//! use jira_issue_api::{Credential, JiraClientConfig};
+//!
+//! let cfg = JiraClientConfig {
+//!     credential: Credential::PersonalAccessToken("xxxxxxx".to_string()),
+//!     url: "https://jira.example.com".to_string(),
+//!     ..Default::default()
+//! };
+//! let client = JiraAPIBlockingClient::new(&cfg)?;
+//!
+//! let issue = client.get_issue(&IssueKey::try_from("JB-1".to_string())?, None)?;
+//! for issue in client.query_issues_stream("project = JB", None, None)? {
+//!     println!("{}", issue?.key);
+//! }
+//! // Anything else, e.g. batch, runs on the client's runtime
+//! let issues = client.block_on(client.as_async().get_issues(&[issue.key], None));
+//! # Ok(())
+//! # }
+//! ```
+use crate::{
+    access::{Access, Authenticated, ReadOnly, Writable},
+    agile::*,
+    assets::*,
+    deployment::{Cloud, CloudDeployment, Configured, DataCenter, Deployment, DeploymentType},
+    fields::FieldRegistry,
+    jql::{JqlValidation, SortOrder},
+    models::*,
+    oauth::OAuth2Tokens,
+    retry::RateLimitInfo,
+    session::SessionLogin,
+    webhooks::{RegisteredWebhook, Webhook, WebhookDetails},
+    ApiVersion, AttachmentUpload, Credential, Impersonation, JiraAPIClient, JiraClientConfig,
+    JiraClientError, Thumbnail,
+};
+use futures_util::{Stream, StreamExt};
+use serde_json::Value;
+use std::{collections::BTreeMap, future::Future, sync::Arc};
+use tokio::runtime::{Builder, Runtime};
+
+/// Blocking wrapper of JiraAPIClient, every method blocks the current thread until it completes
+#[derive(Debug, Clone)]
+pub struct JiraAPIBlockingClient<D: Deployment = Configured, A: Access = Authenticated> {
+    client: JiraAPIClient<D, A>,
+    runtime: Arc<Runtime>,
+}
+
+/// Blocking methods calling the JiraAPIClient method of the same name
+macro_rules! blocking {
+    ($($name:ident($($arg:ident: $ty:ty),* $(,)?) -> $ret:ty;)*) => {
+        $(
+            #[doc = concat!("Blocking [JiraAPIClient::", stringify!($name), "]")]
+            pub fn $name(&self, $($arg: $ty),*) -> $ret {
+                self.block_on(self.client.$name($($arg),*))
+            }
+        )*
+    };
+}
+
+impl JiraAPIBlockingClient {
+    /// Instantiate a reusable blocking API client, see JiraAPIClient::new
+    pub fn new(cfg: &JiraClientConfig) -> Result<JiraAPIBlockingClient, JiraClientError> {
+        JiraAPIBlockingClient::from_async(JiraAPIClient::new(cfg)?)
+    }
+
+    /// Blocking client for Credential::Anonymous with only read methods, see JiraAPIClient::anonymous
+    pub fn anonymous(
+        cfg: &JiraClientConfig,
+    ) -> Result<JiraAPIBlockingClient<Configured, ReadOnly>, JiraClientError> {
+        JiraAPIBlockingClient::from_async(JiraAPIClient::anonymous(cfg)?)
+    }
+}
+
+impl<A: Access> JiraAPIBlockingClient<Configured, A> {
+    /// Blocking [JiraAPIClient::with_detected_deployment]
+    pub fn with_detected_deployment(self) -> Result<Self, JiraClientError> {
+        let client = self.block_on(self.client.clone().with_detected_deployment())?;
+        Ok(JiraAPIBlockingClient { client, ..self })
+    }
+
+    /// See [JiraAPIClient::cloud]
+    pub fn cloud(&self) -> Result<JiraAPIBlockingClient<Cloud, A>, JiraClientError> {
+        Ok(self.wrap(self.client.cloud()?))
+    }
+
+    /// See [JiraAPIClient::data_center]
+    pub fn data_center(&self) -> Result<JiraAPIBlockingClient<DataCenter, A>, JiraClientError> {
+        Ok(self.wrap(self.client.data_center()?))
+    }
+}
+
+impl<D: Deployment, A: Access> JiraAPIBlockingClient<D, A> {
+    /// Wrap an async client, its connection pool is shared
+    pub fn from_async(client: JiraAPIClient<D, A>) -> Result<Self, JiraClientError> {
+        let runtime = Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| JiraClientError::ConfigError(format!("Unable to start runtime: {}", e)))?;
+        Ok(JiraAPIBlockingClient {
+            client,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// The wrapped async client, for methods without a blocking variant e.g. batch
+    pub fn as_async(&self) -> &JiraAPIClient<D, A> {
+        &self.client
+    }
+
+    /// Run a future on the runtime of the client, panics if called from within an async runtime
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    fn wrap<E: Deployment, B: Access>(
+        &self,
+        client: JiraAPIClient<E, B>,
+    ) -> JiraAPIBlockingClient<E, B> {
+        JiraAPIBlockingClient {
+            client,
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Iterator blocking on each item of the stream
+    fn blocking_iter<'a, T>(
+        &'a self,
+        stream: impl Stream<Item = T> + 'a,
+    ) -> impl Iterator<Item = T> + 'a {
+        let mut stream = Box::pin(stream);
+        std::iter::from_fn(move || self.block_on(stream.next()))
+    }
+
+    /// See [JiraAPIClient::with_credential]
+    pub fn with_credential(
+        &self,
+        credential: Credential,
+    ) -> Result<JiraAPIBlockingClient<D, A::WithCredential>, JiraClientError> {
+        Ok(self.wrap(self.client.with_credential(credential)?))
+    }
+
+    /// See [JiraAPIClient::with_impersonation]
+    pub fn with_impersonation(&self, impersonation: Impersonation) -> Self {
+        self.wrap(self.client.with_impersonation(impersonation))
+    }
+
+    /// See [JiraAPIClient::with_api_version]
+    pub fn with_api_version(&self, api_version: ApiVersion) -> Self {
+        self.wrap(self.client.with_api_version(api_version))
+    }
+
+    /// See [JiraAPIClient::with_deployment]
+    pub fn with_deployment<E: Deployment>(&self) -> JiraAPIBlockingClient<E, A> {
+        self.wrap(self.client.with_deployment())
+    }
+
+    /// See [JiraAPIClient::read_only]
+    pub fn read_only(&self) -> JiraAPIBlockingClient<D, ReadOnly> {
+        self.wrap(self.client.read_only())
+    }
+
+    /// See [JiraAPIClient::authenticated]
+    pub fn authenticated(
+        &self,
+    ) -> Result<JiraAPIBlockingClient<D, Authenticated>, JiraClientError> {
+        Ok(self.wrap(self.client.authenticated()?))
+    }
+
+    /// See [JiraAPIClient::deployment]
+    pub fn deployment(&self) -> DeploymentType {
+        self.client.deployment()
+    }
+
+    /// See [JiraAPIClient::rate_limit]
+    pub fn rate_limit(&self) -> Option<RateLimitInfo> {
+        self.client.rate_limit()
+    }
+
+    /// See [JiraAPIClient::oauth2_tokens]
+    pub fn oauth2_tokens(&self) -> Option<OAuth2Tokens> {
+        self.client.oauth2_tokens()
+    }
+
+    /// Blocking [JiraAPIClient::query_issues_stream], each page is fetched when iterated to
+    pub fn query_issues_stream(
+        &self,
+        query: &str,
+        fields: Option<Vec<String>>,
+        expand_options: Option<Vec<String>>,
+    ) -> Result<impl Iterator<Item = Result<Issue, JiraClientError>> + '_, JiraClientError> {
+        let stream = self.block_on(self.client.query_issues_stream(
+            query,
+            fields,
+            expand_options,
+        ))?;
+        Ok(self.blocking_iter(stream))
+    }
+
+    /// Blocking [JiraAPIClient::query_filter_issues], each page is fetched when iterated to
+    pub fn query_filter_issues(
+        &self,
+        filter_id: &str,
+        fields: Option<Vec<String>>,
+        expand_options: Option<Vec<String>>,
+    ) -> Result<impl Iterator<Item = Result<Issue, JiraClientError>> + '_, JiraClientError> {
+        let stream = self.block_on(self.client.query_filter_issues(
+            filter_id,
+            fields,
+            expand_options,
+        ))?;
+        Ok(self.blocking_iter(stream))
+    }
+
+    /// Blocking [JiraAPIClient::get_worklogs_stream], each page is fetched when iterated to
+    pub fn get_worklogs_stream(
+        &self,
+        issue_key: &IssueKey,
+        start_at: u32,
+    ) -> Result<impl Iterator<Item = Result<Worklog, JiraClientError>> + '_, JiraClientError> {
+        let stream = self.block_on(self.client.get_worklogs_stream(issue_key, start_at))?;
+        Ok(self.blocking_iter(stream))
+    }
+
+    /// Blocking [JiraAPIClient::get_worklogs_by_ids_stream]
+    pub fn get_worklogs_by_ids_stream(
+        &self,
+        ids: &[u64],
+    ) -> Result<impl Iterator<Item = Result<Worklog, JiraClientError>> + '_, JiraClientError> {
+        let stream = self.block_on(self.client.get_worklogs_by_ids_stream(ids))?;
+        Ok(self.blocking_iter(stream))
+    }
+
+    /// Blocking [JiraAPIClient::get_audit_records_stream], each page is fetched when iterated to
+    pub fn get_audit_records_stream(
+        &self,
+        params: &GetAuditRecordsParams,
+    ) -> Result<impl Iterator<Item = Result<AuditRecord, JiraClientError>> + '_, JiraClientError>
+    {
+        let stream = self.block_on(self.client.get_audit_records_stream(params))?;
+        Ok(self.blocking_iter(stream))
+    }
+
+    blocking! {
+        query_issues(
+            query: &str,
+            fields: Option<Vec<String>>,
+            expand_options: Option<Vec<String>>,
+        ) -> Result<PostIssueQueryResponseBody, JiraClientError>;
+        query_issues_all(
+            query: &str,
+            fields: Option<Vec<String>>,
+            expand_options: Option<Vec<String>>,
+        ) -> Result<Vec<Issue>, JiraClientError>;
+        validate_jql(queries: &[&str]) -> Result<Vec<JqlValidation>, JiraClientError>;
+        get_issue(
+            issue_key: &IssueKey,
+            expand_options: Option<&str>,
+        ) -> Result<Issue, JiraClientError>;
+        get_issues(
+            issue_keys: &[IssueKey],
+            expand_options: Option<&str>,
+        ) -> Vec<Result<Issue, JiraClientError>>;
+        get_comments(
+            issue_key: &IssueKey,
+            start_at: u32,
+            max_results: Option<u32>,
+            order_by: Option<SortOrder>,
+        ) -> Result<CommentPage, JiraClientError>;
+        get_worklogs(
+            issue_key: &IssueKey,
+            start_at: u32,
+            max_results: Option<u32>,
+        ) -> Result<WorklogPage, JiraClientError>;
+        get_all_worklogs(
+            issue_key: &IssueKey,
+            start_at: u32,
+        ) -> Result<Vec<Worklog>, JiraClientError>;
+        get_worklogs_updated(since: i64) -> Result<WorklogChangePage, JiraClientError>;
+        get_worklogs_deleted(since: i64) -> Result<WorklogChangePage, JiraClientError>;
+        get_worklogs_by_ids(ids: &[u64]) -> Result<Vec<Worklog>, JiraClientError>;
+        get_audit_records(params: &GetAuditRecordsParams) -> Result<AuditRecordPage, JiraClientError>;
+        get_transitions(
+            issue_key: &IssueKey,
+            expand_options: Option<&str>,
+        ) -> Result<GetTransitionsBody, JiraClientError>;
+        get_assignable_users(
+            params: &GetAssignableUserParams,
+        ) -> Result<Vec<User>, JiraClientError>;
+        get_all_assignable_users(
+            params: &GetAssignableUserParams,
+        ) -> Result<Vec<User>, JiraClientError>;
+        get_assignable_users_multi_project(
+            projects: &[&str],
+            query: Option<&str>,
+        ) -> Result<Vec<User>, JiraClientError>;
+        get_viewable_users(params: &GetViewableUserParams) -> Result<Vec<User>, JiraClientError>;
+        find_users_for_picker(
+            query: &str,
+            max_results: Option<u32>,
+            exclude: &[&str],
+        ) -> Result<GetUserPickerResponseBody, JiraClientError>;
+        search_users(
+            query: &str,
+            start_at: u32,
+            max_results: Option<u32>,
+        ) -> Result<Vec<User>, JiraClientError>;
+        get_groups(
+            query: Option<&str>,
+            max_results: Option<u32>,
+        ) -> Result<GetGroupsResponseBody, JiraClientError>;
+        get_group_members(
+            group: &str,
+            start_at: u32,
+            max_results: Option<u32>,
+        ) -> Result<GroupMemberPage, JiraClientError>;
+        get_server_info() -> Result<ServerInfo, JiraClientError>;
+        detect_deployment() -> Result<DeploymentType, JiraClientError>;
+        get_myself() -> Result<User, JiraClientError>;
+        get_user(user: &str) -> Result<User, JiraClientError>;
+        get_create_meta_issue_types(
+            project: &str,
+        ) -> Result<Vec<CreateMetaIssueType>, JiraClientError>;
+        get_create_meta(
+            project: &str,
+            issuetype_id: &str,
+        ) -> Result<Vec<FieldMetadata>, JiraClientError>;
+        get_edit_meta(
+            issue_key: &IssueKey,
+        ) -> Result<BTreeMap<String, FieldMetadata>, JiraClientError>;
+        get_issue_types() -> Result<Vec<IssueType>, JiraClientError>;
+        get_priorities() -> Result<Vec<Priority>, JiraClientError>;
+        get_statuses() -> Result<Vec<Status>, JiraClientError>;
+        get_status_categories() -> Result<Vec<StatusCategoryDetails>, JiraClientError>;
+        suggest_labels(query: &str) -> Result<Vec<String>, JiraClientError>;
+        get_resolutions() -> Result<Vec<Resolution>, JiraClientError>;
+        get_fields() -> Result<Vec<Field>, JiraClientError>;
+        field_registry() -> Result<Arc<FieldRegistry>, JiraClientError>;
+        get_filter(id: &str) -> Result<Filter, JiraClientError>;
+        get_filter_share_permissions(id: &str) -> Result<Vec<SharePermission>, JiraClientError>;
+        get_filter_columns(id: &str) -> Result<Vec<ColumnItem>, JiraClientError>;
+        get_dashboards(start_at: u32) -> Result<DashboardPage, JiraClientError>;
+        get_dashboard(id: &str) -> Result<Dashboard, JiraClientError>;
+        search_filters(
+            filter: Option<&str>,
+        ) -> Result<GetFilterSearchResponseBody, JiraClientError>;
+        get_favourite_filters() -> Result<Vec<Filter>, JiraClientError>;
+        get_security_levels(project_key: &str) -> Result<Vec<SecurityLevel>, JiraClientError>;
+        resolve_security_level(
+            project_key: &str,
+            name: &str,
+        ) -> Result<SecurityLevelRef, JiraClientError>;
+        get_issue_link_types() -> Result<Vec<IssueLinkType>, JiraClientError>;
+        get_remote_links(issue_key: &IssueKey) -> Result<Vec<RemoteLink>, JiraClientError>;
+        get_watchers(issue_key: &IssueKey) -> Result<GetWatchersResponseBody, JiraClientError>;
+        get_votes(issue_key: &IssueKey) -> Result<Votes, JiraClientError>;
+        get_projects() -> Result<Vec<Project>, JiraClientError>;
+        get_project(
+            key_or_id: &str,
+            expand_options: Option<&str>,
+        ) -> Result<Project, JiraClientError>;
+        get_project_statuses(
+            project_key_or_id: &str,
+        ) -> Result<Vec<ProjectIssueTypeStatuses>, JiraClientError>;
+        get_project_roles(
+            project_key_or_id: &str,
+        ) -> Result<BTreeMap<String, String>, JiraClientError>;
+        get_role_actors(
+            project_key_or_id: &str,
+            role_id: u64,
+        ) -> Result<ProjectRole, JiraClientError>;
+        get_component(id: &str) -> Result<Component, JiraClientError>;
+        get_component_related_issue_count(id: &str) -> Result<u64, JiraClientError>;
+        get_issue_property_keys(
+            issue_key: &IssueKey,
+        ) -> Result<Vec<EntityPropertyKey>, JiraClientError>;
+        get_issue_property(
+            issue_key: &IssueKey,
+            property_key: &str,
+        ) -> Result<EntityProperty, JiraClientError>;
+        get_user_property_keys(user: &str) -> Result<Vec<EntityPropertyKey>, JiraClientError>;
+        get_user_property(
+            user: &str,
+            property_key: &str,
+        ) -> Result<EntityProperty, JiraClientError>;
+        get_attachment_meta(id: &str) -> Result<Attachment, JiraClientError>;
+        get_attachment_thumbnail(id: &str) -> Result<Option<Thumbnail>, JiraClientError>;
+        get_project_versions(project_key_or_id: &str) -> Result<Vec<Version>, JiraClientError>;
+        get_version(id: &str) -> Result<Version, JiraClientError>;
+        get_boards(
+            project: Option<&str>,
+            board_type: Option<BoardType>,
+            name: Option<&str>,
+        ) -> Result<Vec<Board>, JiraClientError>;
+        get_board(board_id: u64) -> Result<Board, JiraClientError>;
+        get_sprints(board_id: u64, states: &[SprintState]) -> Result<Vec<Sprint>, JiraClientError>;
+        get_sprint(sprint_id: u64) -> Result<Sprint, JiraClientError>;
+        get_sprint_issues(
+            sprint_id: u64,
+            jql: Option<&str>,
+            start_at: u32,
+        ) -> Result<AgileIssuePage, JiraClientError>;
+        get_epics(board_id: u64) -> Result<Vec<Epic>, JiraClientError>;
+        get_issues_for_epic(epic: &str, start_at: u32) -> Result<AgileIssuePage, JiraClientError>;
+        get_issues_without_epic(
+            board_id: Option<u64>,
+            start_at: u32,
+        ) -> Result<AgileIssuePage, JiraClientError>;
+        get_board_configuration(board_id: u64) -> Result<BoardConfiguration, JiraClientError>;
+        get_object_schemas() -> Result<GetObjectSchemaListResponseBody, JiraClientError>;
+        query_assets(
+            iql: &str,
+            page: u32,
+            include_attributes: bool,
+        ) -> Result<GetIqlObjectsResponseBody, JiraClientError>;
+        get_object_attributes(object_id: u32) -> Result<Vec<ObjectAttribute>, JiraClientError>;
+        generate_fields_struct(
+            struct_name: &str,
+            field_ids: &[&str],
+        ) -> Result<String, JiraClientError>;
+        generate_create_meta_struct(
+            struct_name: &str,
+            project: &str,
+            issuetype_id: &str,
+        ) -> Result<String, JiraClientError>;
+        login() -> Result<SessionLogin, JiraClientError>;
+        logout() -> Result<(), JiraClientError>;
+        list_webhooks() -> Result<Vec<Webhook>, JiraClientError>;
+    }
+}
+
+impl<D: CloudDeployment, A: Access> JiraAPIBlockingClient<D, A> {
+    blocking! {
+        get_my_filters(include_favourites: bool) -> Result<Vec<Filter>, JiraClientError>;
+        get_dashboard_gadgets(dashboard_id: &str) -> Result<Vec<DashboardGadget>, JiraClientError>;
+        search_jql(
+            body: &PostEnhancedSearchBody,
+        ) -> Result<EnhancedSearchResponseBody, JiraClientError>;
+        search_jql_all(body: &PostEnhancedSearchBody) -> Result<Vec<Issue>, JiraClientError>;
+        get_labels() -> Result<Vec<String>, JiraClientError>;
+        get_changelog(
+            issue_key: &IssueKey,
+            start_at: u32,
+            max_results: Option<u32>,
+        ) -> Result<GetChangelogResponseBody, JiraClientError>;
+        get_failed_webhooks(
+            after: Option<i64>,
+        ) -> Result<GetFailedWebhooksResponseBody, JiraClientError>;
+        get_all_failed_webhooks(after: Option<i64>) -> Result<Vec<FailedWebhook>, JiraClientError>;
+    }
+}
+
+impl<D: Deployment, A: Writable> JiraAPIBlockingClient<D, A> {
+    blocking! {
+        create_issue(body: &PostIssueBody) -> Result<CreatedIssueResponse, JiraClientError>;
+        edit_issue(
+            issue_key: &IssueKey,
+            body: &EditIssueBody,
+            notify_users: bool,
+        ) -> Result<(), JiraClientError>;
+        post_worklog(
+            issue_key: &IssueKey,
+            body: PostWorklogBody,
+            adjust_estimate: &AdjustEstimate,
+        ) -> Result<Worklog, JiraClientError>;
+        update_worklog(
+            issue_key: &IssueKey,
+            worklog_id: &str,
+            body: PostWorklogBody,
+        ) -> Result<Worklog, JiraClientError>;
+        delete_worklog(
+            issue_key: &IssueKey,
+            worklog_id: &str,
+            adjust_estimate: &AdjustEstimate,
+        ) -> Result<(), JiraClientError>;
+        post_comment(
+            issue_key: &IssueKey,
+            body: PostCommentBody,
+        ) -> Result<Comment, JiraClientError>;
+        update_comment(
+            issue_key: &IssueKey,
+            comment_id: &str,
+            body: PostCommentBody,
+        ) -> Result<Comment, JiraClientError>;
+        delete_comment(issue_key: &IssueKey, comment_id: &str) -> Result<(), JiraClientError>;
+        post_transition(
+            issue_key: &IssueKey,
+            transition: &PostTransitionBody,
+        ) -> Result<(), JiraClientError>;
+        post_assign_user(issue_key: &IssueKey, user: &User) -> Result<(), JiraClientError>;
+        upload_attachment(
+            issue_key: &IssueKey,
+            upload: AttachmentUpload,
+            progress: impl Fn(u64, Option<u64>) + Send + Sync + 'static,
+        ) -> Result<Vec<Attachment>, JiraClientError>;
+        upload_attachments(
+            issue_key: &IssueKey,
+            uploads: Vec<AttachmentUpload>,
+            concurrency: usize,
+            progress: impl Fn(&str, u64, Option<u64>) + Send + Sync + 'static,
+        ) -> Vec<Result<Vec<Attachment>, JiraClientError>>;
+        assign_issue(issue_key: &IssueKey, assignee: &AssigneeRef) -> Result<(), JiraClientError>;
+        create_issue_link(
+            inward: &IssueKey,
+            outward: &IssueKey,
+            link_type: &str,
+            comment: Option<PostCommentBody>,
+        ) -> Result<(), JiraClientError>;
+        delete_issue_link(id: &str) -> Result<(), JiraClientError>;
+        create_remote_link(
+            issue_key: &IssueKey,
+            body: &PostRemoteLinkBody,
+        ) -> Result<PostRemoteLinkResponseBody, JiraClientError>;
+        delete_remote_link(issue_key: &IssueKey, id: u64) -> Result<(), JiraClientError>;
+        delete_remote_link_by_global_id(
+            issue_key: &IssueKey,
+            global_id: &str,
+        ) -> Result<(), JiraClientError>;
+        add_watcher(issue_key: &IssueKey, user: &str) -> Result<(), JiraClientError>;
+        remove_watcher(issue_key: &IssueKey, user: &str) -> Result<(), JiraClientError>;
+        add_vote(issue_key: &IssueKey) -> Result<(), JiraClientError>;
+        remove_vote(issue_key: &IssueKey) -> Result<(), JiraClientError>;
+        add_user_to_group(group: &str, user: &str) -> Result<(), JiraClientError>;
+        remove_user_from_group(group: &str, user: &str) -> Result<(), JiraClientError>;
+        set_issue_property(
+            issue_key: &IssueKey,
+            property_key: &str,
+            value: &Value,
+        ) -> Result<(), JiraClientError>;
+        delete_issue_property(
+            issue_key: &IssueKey,
+            property_key: &str,
+        ) -> Result<(), JiraClientError>;
+        set_user_property(
+            user: &str,
+            property_key: &str,
+            value: &Value,
+        ) -> Result<(), JiraClientError>;
+        delete_user_property(user: &str, property_key: &str) -> Result<(), JiraClientError>;
+        add_role_actors(
+            project_key_or_id: &str,
+            role_id: u64,
+            body: &PostRoleActorsBody,
+        ) -> Result<ProjectRole, JiraClientError>;
+        remove_role_actor(
+            project_key_or_id: &str,
+            role_id: u64,
+            actor: &RoleActorRef,
+        ) -> Result<(), JiraClientError>;
+        create_component(body: &PostComponentBody) -> Result<Component, JiraClientError>;
+        update_component(id: &str, body: &PutComponentBody) -> Result<Component, JiraClientError>;
+        delete_component(id: &str, move_issues_to: Option<&str>) -> Result<(), JiraClientError>;
+        create_filter(body: &PostFilterBody) -> Result<Filter, JiraClientError>;
+        update_filter(id: &str, body: &PostFilterBody) -> Result<Filter, JiraClientError>;
+        delete_filter(id: &str) -> Result<(), JiraClientError>;
+        set_filter_favourite(id: &str, favourite: bool) -> Result<Filter, JiraClientError>;
+        set_filter_columns(id: &str, fields: &[&str]) -> Result<(), JiraClientError>;
+        add_filter_share_permission(
+            id: &str,
+            body: &PostSharePermissionBody,
+        ) -> Result<Vec<SharePermission>, JiraClientError>;
+        delete_filter_share_permission(id: &str, permission_id: u64) -> Result<(), JiraClientError>;
+        delete_attachment(id: &str) -> Result<(), JiraClientError>;
+        create_version(body: &PostVersionBody) -> Result<Version, JiraClientError>;
+        update_version(id: &str, body: &PutVersionBody) -> Result<Version, JiraClientError>;
+        archive_version(id: &str) -> Result<Version, JiraClientError>;
+        unarchive_version(id: &str) -> Result<Version, JiraClientError>;
+        delete_version(
+            id: &str,
+            move_fixed_to: Option<&str>,
+            move_affected_to: Option<&str>,
+        ) -> Result<(), JiraClientError>;
+        move_version(id: &str, body: &PostVersionMoveBody) -> Result<Version, JiraClientError>;
+        merge_version(id: &str, into_id: &str) -> Result<(), JiraClientError>;
+        create_sprint(body: &PostSprintBody) -> Result<Sprint, JiraClientError>;
+        update_sprint(sprint_id: u64, body: &PutSprintBody) -> Result<Sprint, JiraClientError>;
+        move_issues_to_sprint(
+            sprint_id: u64,
+            issue_keys: &[IssueKey],
+        ) -> Result<(), JiraClientError>;
+        move_issues_to_backlog(issue_keys: &[IssueKey]) -> Result<(), JiraClientError>;
+        move_issues_to_epic(
+            epic: Option<&str>,
+            issue_keys: &[IssueKey],
+        ) -> Result<(), JiraClientError>;
+        delete_sprint(sprint_id: u64) -> Result<(), JiraClientError>;
+        register_webhooks(
+            url: &str,
+            webhooks: &[WebhookDetails],
+        ) -> Result<Vec<RegisteredWebhook>, JiraClientError>;
+        delete_webhooks(ids: &[i64]) -> Result<(), JiraClientError>;
+    }
+}
+
+impl<D: CloudDeployment, A: Writable> JiraAPIBlockingClient<D, A> {
+    blocking! {
+        refresh_webhooks(ids: &[i64]) -> Result<PutWebhookRefreshResponseBody, JiraClientError>;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn typestates_share_runtime() -> Result<(), JiraClientError> {
+        let client = JiraAPIBlockingClient::new(&JiraClientConfig {
+            credential: Credential::PersonalAccessToken("token".to_string()),
+            url: "https://jira.example.com".to_string(),
+            ..Default::default()
+        })?;
+        let read_only = client.read_only();
+        assert!(Arc::ptr_eq(&client.runtime, &read_only.runtime));
+        assert_eq!(2, read_only.block_on(async { 1 + 1 }));
+        Ok(())
+    }
+}
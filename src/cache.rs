@@ -0,0 +1,188 @@
+//! Opt-in cache of GET responses, see JiraClientConfig::response_cache_entries.
+//!
+//! JSON responses carrying an ETag or Last-Modified header are stored, and every later GET of the same url
+//! is sent with If-None-Match or If-Modified-Since. A 304 response is answered with the stored body.
+//! Responses are always revalidated, Cache-Control is ignored.
+use crate::client::read_body;
+use crate::JiraClientError;
+use reqwest::header::{
+    HeaderMap, CONTENT_TYPE, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED,
+};
+use reqwest::{Request, Response, ResponseBuilderExt, StatusCode, Url};
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::{Debug, Formatter},
+    sync::{Arc, Mutex},
+};
+use tokio_util::bytes::Bytes;
+
+/// Shared by clones of a client with the same credential and impersonation
+pub(crate) struct ResponseCache {
+    max_entries: usize,
+    entries: Mutex<Entries>,
+}
+
+#[derive(Default)]
+struct Entries {
+    responses: HashMap<String, Arc<CachedResponse>>,
+    /// Urls by insertion, the oldest is evicted first
+    order: VecDeque<String>,
+}
+
+pub(crate) struct CachedResponse {
+    url: Url,
+    headers: HeaderMap,
+    body: Bytes,
+}
+
+impl ResponseCache {
+    pub(crate) fn new(max_entries: usize) -> Self {
+        ResponseCache {
+            max_entries: max_entries.max(1),
+            entries: Mutex::default(),
+        }
+    }
+
+    /// Empty cache with the same capacity, e.g. for a client with another credential
+    pub(crate) fn empty(&self) -> Self {
+        ResponseCache::new(self.max_entries)
+    }
+
+    /// Add the validators of the cached response for the url, if any
+    pub(crate) fn prepare(&self, request: &mut Request) -> Option<Arc<CachedResponse>> {
+        let cached = self
+            .entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .responses
+            .get(request.url().as_str())
+            .cloned()?;
+
+        let headers = request.headers_mut();
+        if let Some(etag) = cached.headers.get(ETAG) {
+            headers.insert(IF_NONE_MATCH, etag.clone());
+        } else if let Some(last_modified) = cached.headers.get(LAST_MODIFIED) {
+            headers.insert(IF_MODIFIED_SINCE, last_modified.clone());
+        }
+        Some(cached)
+    }
+
+    /// Store the response if it can be revalidated, the body is read and returned in a new response
+    pub(crate) async fn store(
+        &self,
+        key: String,
+        response: Response,
+    ) -> Result<Response, JiraClientError> {
+        let cacheable = response.status() == StatusCode::OK
+            && (response.headers().contains_key(ETAG)
+                || response.headers().contains_key(LAST_MODIFIED))
+            && response
+                .headers()
+                .get(CONTENT_TYPE)
+                .and_then(|content_type| content_type.to_str().ok())
+                .is_some_and(|content_type| content_type.contains("json"));
+        if !cacheable {
+            return Ok(response);
+        }
+
+        let url = response.url().clone();
+        let headers = response.headers().clone();
+        let body = Bytes::from(read_body(response).await?);
+        let cached = Arc::new(CachedResponse { url, headers, body });
+        self.insert(key, cached.clone());
+        Ok(cached.to_response())
+    }
+
+    fn insert(&self, key: String, cached: Arc<CachedResponse>) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        if entries.responses.insert(key.clone(), cached).is_none() {
+            entries.order.push_back(key);
+        }
+        while entries.order.len() > self.max_entries {
+            if let Some(oldest) = entries.order.pop_front() {
+                entries.responses.remove(&oldest);
+            }
+        }
+    }
+}
+
+impl Debug for ResponseCache {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ResponseCache")
+            .field("max_entries", &self.max_entries)
+            .finish_non_exhaustive()
+    }
+}
+
+impl CachedResponse {
+    /// 200 response with the stored headers and body
+    pub(crate) fn to_response(&self) -> Response {
+        let mut response = http::Response::builder()
+            .status(StatusCode::OK)
+            .url(self.url.clone())
+            .body(self.body.clone())
+            .expect("status and url are valid");
+        *response.headers_mut() = self.headers.clone();
+        Response::from(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::FutureExt;
+    use reqwest::{header::HeaderValue, Method};
+
+    fn response(url: &Url, etag: Option<&'static str>) -> Response {
+        let mut builder = http::Response::builder()
+            .status(StatusCode::OK)
+            .url(url.clone())
+            .header(CONTENT_TYPE, "application/json;charset=UTF-8");
+        if let Some(etag) = etag {
+            builder = builder.header(ETAG, etag);
+        }
+        Response::from(builder.body(r#"{"key":"JB-1"}"#).unwrap())
+    }
+
+    #[test]
+    fn revalidates_cached_responses() -> Result<(), JiraClientError> {
+        let cache = ResponseCache::new(1);
+        let url = Url::parse("https://jira.example.com/rest/api/latest/issue/JB-1")?;
+        let other = Url::parse("https://jira.example.com/rest/api/latest/issue/JB-2")?;
+
+        let mut request = Request::new(Method::GET, url.clone());
+        assert!(cache.prepare(&mut request).is_none());
+
+        cache
+            .store(url.to_string(), response(&url, None))
+            .now_or_never()
+            .unwrap()?;
+        assert!(cache.prepare(&mut request).is_none());
+
+        cache
+            .store(url.to_string(), response(&url, Some("\"v1\"")))
+            .now_or_never()
+            .unwrap()?;
+        let cached = cache.prepare(&mut request).unwrap();
+        assert_eq!(
+            Some(&HeaderValue::from_static("\"v1\"")),
+            request.headers().get(IF_NONE_MATCH)
+        );
+        let replayed = cached.to_response();
+        assert_eq!(&url, replayed.url());
+        assert_eq!(
+            r#"{"key":"JB-1"}"#,
+            replayed.text().now_or_never().unwrap()?
+        );
+
+        // Evicted by the next response
+        cache
+            .store(other.to_string(), response(&other, Some("\"v1\"")))
+            .now_or_never()
+            .unwrap()?;
+        assert!(cache
+            .prepare(&mut Request::new(Method::GET, url.clone()))
+            .is_none());
+        Ok(())
+    }
+}
@@ -0,0 +1,267 @@
+//! Generate typed field structs from Jira field metadata
+//!
+//! Output derives `JiraFields`, so it requires the `derive` feature where it is compiled.
+//!
+//! ```rust,no_run
+//! # async fn generate(client: jira_issue_api::JiraAPIClient) -> Result<(), jira_issue_api::JiraClientError> {
+//! let source = client
+//!     .generate_fields_struct("Estimate", &["summary", "customfield_10016"])
+//!     .await?;
+//! std::fs::write("src/estimate.rs", source).unwrap();
+//! # Ok(())
+//! # }
+//! ```
+use crate::{
+    access::Access,
+    deployment::Deployment,
+    models::{Field, FieldMetadata, FieldSchema},
+    JiraAPIClient, JiraClientError,
+};
+use std::collections::HashSet;
+use std::fmt::Write;
+
+/// Field metadata needed to generate a struct field
+#[derive(Debug, Clone)]
+pub struct FieldMeta<'a> {
+    pub id: &'a str,
+    pub name: &'a str,
+    pub schema: Option<&'a FieldSchema>,
+}
+
+impl<'a> From<&'a Field> for FieldMeta<'a> {
+    fn from(field: &'a Field) -> Self {
+        FieldMeta {
+            id: &field.id,
+            name: &field.name,
+            schema: field.schema.as_ref(),
+        }
+    }
+}
+
+impl<'a> From<&'a FieldMetadata> for FieldMeta<'a> {
+    fn from(field: &'a FieldMetadata) -> Self {
+        FieldMeta {
+            id: &field.field_id,
+            name: &field.name,
+            schema: Some(&field.schema),
+        }
+    }
+}
+
+/// Strict and reserved keywords of all editions
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Rust type for a field schema, unknown types fall back to serde_json::Value
+pub fn rust_type(schema: &FieldSchema) -> &'static str {
+    fn scalar(field_type: &str) -> &'static str {
+        match field_type {
+            "string" | "date" | "datetime" => "String",
+            "number" => "f64",
+            "user" => "jira_issue_api::models::User",
+            "status" => "jira_issue_api::models::Status",
+            "component" => "jira_issue_api::models::Component",
+            _ => "serde_json::Value",
+        }
+    }
+
+    match schema.field_type.as_deref() {
+        Some("array") => {
+            let items = schema
+                .items
+                .as_ref()
+                .and_then(|items| serde_json::to_value(items).ok())
+                .and_then(|items| items.as_str().map(scalar));
+            match items {
+                Some("String") => "Vec<String>",
+                Some("f64") => "Vec<f64>",
+                Some("jira_issue_api::models::User") => "Vec<jira_issue_api::models::User>",
+                Some("jira_issue_api::models::Component") => {
+                    "Vec<jira_issue_api::models::Component>"
+                }
+                _ => "Vec<serde_json::Value>",
+            }
+        }
+        Some(field_type) => scalar(field_type),
+        None => "serde_json::Value",
+    }
+}
+
+fn snake_case(name: &str) -> String {
+    let mut ident = String::new();
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            ident.push(c.to_ascii_lowercase());
+        } else if !ident.is_empty() && !ident.ends_with('_') {
+            ident.push('_');
+        }
+    }
+    ident.trim_end_matches('_').to_string()
+}
+
+/// snake_case identifier for a field name, e.g. Story Points -> story_points.
+/// Names without letters or digits fall back to the field id, e.g. ? -> field_customfield_10016
+pub fn field_ident(name: &str, id: &str) -> String {
+    let mut ident = snake_case(name);
+
+    if ident.is_empty() {
+        ident = format!("field_{}", snake_case(id));
+    } else if ident.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.insert(0, '_');
+    }
+    if KEYWORDS.contains(&ident.as_str()) {
+        ident.push('_');
+    }
+    ident
+}
+
+/// Rust source for a struct deriving JiraFields, fields without schema are skipped.
+/// Every field is optional as fields can be hidden from the issue screen.
+pub fn generate_struct<'a>(
+    struct_name: &str,
+    fields: impl IntoIterator<Item = FieldMeta<'a>>,
+) -> String {
+    let mut idents = HashSet::new();
+    let mut source = String::new();
+
+    let _ = writeln!(
+        source,
+        "#[derive(jira_issue_api::fields::JiraFields, Debug, Clone)]"
+    );
+    let _ = writeln!(source, "pub struct {} {{", struct_name);
+    for field in fields {
+        let Some(schema) = field.schema else {
+            continue;
+        };
+
+        let mut ident = field_ident(field.name, field.id);
+        if !idents.insert(ident.clone()) {
+            ident = format!("{}_{}", ident.trim_end_matches('_'), snake_case(field.id));
+            idents.insert(ident.clone());
+        }
+
+        let _ = writeln!(source, "    /// {}", field.name);
+        let _ = writeln!(source, "    #[jira(field = {:?})]", field.id);
+        let _ = writeln!(source, "    pub {}: Option<{}>,", ident, rust_type(schema));
+    }
+    let _ = writeln!(source, "}}");
+    source
+}
+
+impl<D: Deployment, A: Access> JiraAPIClient<D, A> {
+    /// Generate a struct for field_ids from the instance field metadata,
+    /// all custom fields if field_ids is empty.
+    pub async fn generate_fields_struct(
+        &self,
+        struct_name: &str,
+        field_ids: &[&str],
+    ) -> Result<String, JiraClientError> {
+        let fields = self.get_fields().await?;
+
+        let selected = if field_ids.is_empty() {
+            fields.iter().filter(|f| f.custom).collect::<Vec<_>>()
+        } else {
+            field_ids
+                .iter()
+                .map(|id| {
+                    fields.iter().find(|f| f.id == *id).ok_or_else(|| {
+                        JiraClientError::ConfigError(format!("Field '{}' not found", id))
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?
+        };
+
+        Ok(generate_struct(
+            struct_name,
+            selected.into_iter().map(FieldMeta::from),
+        ))
+    }
+
+    /// Generate a struct for the fields on the create screen of a project issue type
+    pub async fn generate_create_meta_struct(
+        &self,
+        struct_name: &str,
+        project: &str,
+        issuetype_id: &str,
+    ) -> Result<String, JiraClientError> {
+        let fields = self.get_create_meta(project, issuetype_id).await?;
+        Ok(generate_struct(
+            struct_name,
+            fields.iter().map(FieldMeta::from),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(id: &str, name: &str, schema: serde_json::Value) -> Field {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "name": name,
+            "custom": id.starts_with("customfield_"),
+            "orderable": true,
+            "navigable": true,
+            "searchable": true,
+            "clauseNames": [],
+            "schema": schema,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn field_ident_sanitizes_names() {
+        let id = "customfield_10016";
+        assert_eq!("story_points", field_ident("Story Points", id));
+        assert_eq!("epic_link", field_ident("Epic-Link ", id));
+        assert_eq!("_3rd_party", field_ident("3rd party", id));
+        assert_eq!("type_", field_ident("Type", id));
+        assert_eq!("try_", field_ident("Try", id));
+        assert_eq!("field_customfield_10016", field_ident("?", id));
+    }
+
+    #[test]
+    fn generate_struct_maps_schema_types() {
+        let fields = [
+            field(
+                "customfield_10016",
+                "Story Points",
+                serde_json::json!({"type": "number", "custom": "com.atlassian.jira.plugin.system.customfieldtypes:float", "customId": 10016}),
+            ),
+            field(
+                "labels",
+                "Labels",
+                serde_json::json!({"type": "array", "items": "string", "system": "labels"}),
+            ),
+            field(
+                "customfield_10020",
+                "Story Points",
+                serde_json::json!({"type": "option"}),
+            ),
+        ];
+
+        let source = generate_struct("Estimate", fields.iter().map(FieldMeta::from));
+        assert_eq!(
+            r#"#[derive(jira_issue_api::fields::JiraFields, Debug, Clone)]
+pub struct Estimate {
+    /// Story Points
+    #[jira(field = "customfield_10016")]
+    pub story_points: Option<f64>,
+    /// Labels
+    #[jira(field = "labels")]
+    pub labels: Option<Vec<String>>,
+    /// Story Points
+    #[jira(field = "customfield_10020")]
+    pub story_points_customfield_10020: Option<serde_json::Value>,
+}
+"#,
+            source
+        );
+    }
+}
@@ -0,0 +1,235 @@
+//! Atlassian Connect JWT authentication, enabled with the `connect` feature.
+//!
+//! Every request is signed with a HS256 JWT whose qsh claim covers the method, path and query,
+//! using the shared secret received in the installed lifecycle callback.
+//!
+//! ```rust,no_run
+//! use jira_issue_api::{Credential, JiraAPIClient, JiraClientConfig};
+//!
+//! let cfg = JiraClientConfig {
+//!     credential: Credential::ConnectJwt {
+//!         shared_secret: "shared secret from the installed callback".to_string(),
+//!         app_key: "com.example.my-app".to_string(),
+//!     },
+//!     url: "https://domain.atlassian.net".to_string(),
+//!     ..Default::default()
+//! };
+//! let client = JiraAPIClient::new(&cfg).unwrap();
+//! ```
+use crate::{client::now, Credential, JiraClientError};
+use base64::{engine::general_purpose, Engine as _};
+use reqwest::{header::HeaderValue, Method, Url};
+use ring::{digest, hmac};
+use serde::Serialize;
+use std::{
+    fmt::{Debug, Formatter},
+    sync::Arc,
+    time::{Duration, UNIX_EPOCH},
+};
+
+/// Token lifetime, each token is only used for the request it was signed for
+const LIFETIME: Duration = Duration::from_secs(180);
+
+#[derive(Serialize)]
+struct Header {
+    alg: &'static str,
+    typ: &'static str,
+}
+
+#[derive(Serialize)]
+struct Claims<'a> {
+    iss: &'a str,
+    iat: u64,
+    exp: u64,
+    qsh: String,
+}
+
+/// Signs every request of a client
+pub(crate) struct ConnectSigner {
+    app_key: String,
+    key: hmac::Key,
+}
+
+impl ConnectSigner {
+    pub(crate) fn from_credential(credential: &Credential) -> Option<Arc<ConnectSigner>> {
+        match credential {
+            Credential::ConnectJwt {
+                shared_secret,
+                app_key,
+            } => Some(Arc::new(ConnectSigner {
+                app_key: app_key.clone(),
+                key: hmac::Key::new(hmac::HMAC_SHA256, shared_secret.as_bytes()),
+            })),
+            _ => None,
+        }
+    }
+
+    /// Authorization header for the request, base is the site url the path is relative to
+    pub(crate) fn header(
+        &self,
+        method: &Method,
+        url: &Url,
+        base: &Url,
+    ) -> Result<HeaderValue, JiraClientError> {
+        let now = now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        let claims = Claims {
+            iss: &self.app_key,
+            iat: now.as_secs(),
+            exp: (now + LIFETIME).as_secs(),
+            qsh: query_string_hash(method, url, base),
+        };
+        let header = Header {
+            alg: "HS256",
+            typ: "JWT",
+        };
+
+        let message = format!("{}.{}", encode(&header)?, encode(&claims)?);
+        let signature = hmac::sign(&self.key, message.as_bytes());
+        let token = format!(
+            "{}.{}",
+            message,
+            general_purpose::URL_SAFE_NO_PAD.encode(signature.as_ref())
+        );
+
+        let mut header = HeaderValue::from_str(&format!("JWT {}", token))
+            .map_err(|e| JiraClientError::ConfigError(format!("Invalid Connect JWT: {}", e)))?;
+        header.set_sensitive(true);
+        Ok(header)
+    }
+}
+
+impl Debug for ConnectSigner {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConnectSigner")
+            .field("app_key", &self.app_key)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Hex encoded SHA-256 of the canonical request
+fn query_string_hash(method: &Method, url: &Url, base: &Url) -> String {
+    digest::digest(
+        &digest::SHA256,
+        canonical_request(method, url, base).as_bytes(),
+    )
+    .as_ref()
+    .iter()
+    .map(|b| format!("{:02x}", b))
+    .collect()
+}
+
+/// METHOD&path&query, the path is relative to the context path of the site
+/// and the query is sorted by key with values of repeated keys joined by ','.
+fn canonical_request(method: &Method, url: &Url, base: &Url) -> String {
+    let context_path = base.path().trim_end_matches('/');
+    let path = url
+        .path()
+        .strip_prefix(context_path)
+        .unwrap_or(url.path())
+        .trim_end_matches('/');
+    let path = match path.is_empty() {
+        true => "/".to_string(),
+        false => path.replace('&', "%26"),
+    };
+
+    let mut params = std::collections::BTreeMap::<String, Vec<String>>::new();
+    for (key, value) in url.query_pairs().filter(|(key, _)| key != "jwt") {
+        params
+            .entry(percent_encode(&key))
+            .or_default()
+            .push(percent_encode(&value));
+    }
+    let query = params
+        .into_iter()
+        .map(|(key, mut values)| {
+            values.sort();
+            format!("{}={}", key, values.join(","))
+        })
+        .collect::<Vec<_>>()
+        .join("&");
+
+    format!("{}&{}&{}", method.as_str().to_uppercase(), path, query)
+}
+
+/// RFC 3986, everything but unreserved characters is encoded
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+fn encode<T: Serialize>(value: &T) -> Result<String, JiraClientError> {
+    let json = serde_json::to_vec(value).map_err(|e| {
+        JiraClientError::UnknownError(format!("Unable to encode Connect JWT: {}", e))
+    })?;
+    Ok(general_purpose::URL_SAFE_NO_PAD.encode(json))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canonical_request_sorts_and_encodes_query() -> Result<(), url::ParseError> {
+        let base = Url::parse("https://example.com/jira/")?;
+        let url = Url::parse(
+            "https://example.com/jira/rest/api/latest/search/?jql=project%20%3D%20JB&fields=key&fields=id&expand=a,b&jwt=old",
+        )?;
+        assert_eq!(
+            "GET&/rest/api/latest/search&expand=a%2Cb&fields=id,key&jql=project%20%3D%20JB",
+            canonical_request(&Method::GET, &url, &base)
+        );
+        assert_eq!(
+            "POST&/&",
+            canonical_request(
+                &Method::POST,
+                &Url::parse("https://example.com/jira")?,
+                &base
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn connect_token_claims_and_signature() -> Result<(), JiraClientError> {
+        let signer = ConnectSigner::from_credential(&Credential::ConnectJwt {
+            shared_secret: "secret".to_string(),
+            app_key: "com.example.app".to_string(),
+        })
+        .unwrap();
+        let base = Url::parse("https://domain.atlassian.net/")?;
+        let url = base.join("rest/api/latest/issue/JB-1")?;
+
+        let header = signer.header(&Method::GET, &url, &base)?;
+        assert!(header.is_sensitive());
+        let token = header.to_str().unwrap().strip_prefix("JWT ").unwrap();
+        let (message, signature) = token.rsplit_once('.').unwrap();
+
+        let claims: serde_json::Value = serde_json::from_slice(
+            &general_purpose::URL_SAFE_NO_PAD
+                .decode(message.split('.').nth(1).unwrap())
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!("com.example.app", claims["iss"]);
+        assert_eq!(
+            query_string_hash(&Method::GET, &url, &base),
+            claims["qsh"].as_str().unwrap()
+        );
+
+        let key = hmac::Key::new(hmac::HMAC_SHA256, b"secret");
+        hmac::verify(
+            &key,
+            message.as_bytes(),
+            &general_purpose::URL_SAFE_NO_PAD.decode(signature).unwrap(),
+        )
+        .unwrap();
+        Ok(())
+    }
+}
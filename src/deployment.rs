@@ -0,0 +1,125 @@
+//! Typestates for JiraAPIClient, deployment specific methods are only callable on the right variant.
+//!
+//! ```rust
+//! use jira_issue_api::deployment::{Cloud, DataCenter};
+//! use jira_issue_api::{Credential, JiraAPIClient, JiraClientConfig};
+//!
+//! let cfg = JiraClientConfig {
+//!     credential: Credential::PersonalAccessToken("xxxxxxx".to_string()),
+//!     url: "https://domain.atlassian.net".to_string(),
+//!     ..Default::default()
+//! };
+//! let client = JiraAPIClient::new(&cfg).unwrap();
+//!
+//! let cloud: JiraAPIClient<Cloud> = client.with_deployment();
+//! // cloud.get_labels().await
+//!
+//! let data_center: JiraAPIClient<DataCenter> = client.with_deployment();
+//! // data_center.get_labels().await // Does not compile
+//! ```
+//!
+//! ```rust,compile_fail
+//! use jira_issue_api::deployment::DataCenter;
+//! use jira_issue_api::JiraAPIClient;
+//!
+//! async fn labels(client: JiraAPIClient<DataCenter>) {
+//!     let _ = client.get_labels().await;
+//! }
+//! ```
+//!
+//! The deployment selects query parameters and available methods.
+//! The default `Configured` typestate selects query parameters at runtime by
+//! `JiraClientConfig::deployment`, or by serverInfo with `with_detected_deployment`.
+//! It has no deployment specific methods, `cloud` and `data_center` check the deployment and convert it.
+//!
+//! ```rust,no_run
+//! # async fn detect() -> Result<(), jira_issue_api::JiraClientError> {
+//! use jira_issue_api::{Credential, JiraAPIClient, JiraClientConfig};
+//!
+//! let cfg = JiraClientConfig {
+//!     credential: Credential::PersonalAccessToken("xxxxxxx".to_string()),
+//!     url: "https://jira.example.com".to_string(),
+//!     ..Default::default()
+//! };
+//! let client = JiraAPIClient::new(&cfg)?.with_detected_deployment().await?;
+//! if let Ok(cloud) = client.cloud() {
+//!     let labels = cloud.get_labels().await?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use std::fmt::Debug;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+pub trait Deployment: sealed::Sealed + Debug + Clone + Send + Sync + 'static {
+    /// Selects cloud query parameters, e.g. accountId instead of username.
+    /// None for the deployment configured at runtime.
+    const TYPE: Option<DeploymentType>;
+}
+
+/// Methods only available on Jira Cloud
+pub trait CloudDeployment: Deployment {}
+
+/// Jira Cloud
+#[derive(Debug, Clone, Copy)]
+pub struct Cloud;
+
+/// Jira Data Center/Server
+#[derive(Debug, Clone, Copy)]
+pub struct DataCenter;
+
+/// Deployment selected at runtime by JiraClientConfig::deployment,
+/// use JiraAPIClient::cloud for the cloud only methods
+#[derive(Debug, Clone, Copy)]
+pub struct Configured;
+
+impl sealed::Sealed for Cloud {}
+impl sealed::Sealed for DataCenter {}
+impl sealed::Sealed for Configured {}
+
+impl Deployment for Cloud {
+    const TYPE: Option<DeploymentType> = Some(DeploymentType::Cloud);
+}
+
+impl Deployment for DataCenter {
+    const TYPE: Option<DeploymentType> = Some(DeploymentType::DataCenter);
+}
+
+impl Deployment for Configured {
+    const TYPE: Option<DeploymentType> = None;
+}
+
+impl CloudDeployment for Cloud {}
+
+/// Deployment of a Jira instance, as reported by serverInfo
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeploymentType {
+    Cloud,
+    /// Data Center and Server
+    DataCenter,
+}
+
+/// Selected by the `cloud` cargo feature
+impl Default for DeploymentType {
+    fn default() -> Self {
+        match cfg!(feature = "cloud") {
+            true => DeploymentType::Cloud,
+            false => DeploymentType::DataCenter,
+        }
+    }
+}
+
+impl DeploymentType {
+    /// deploymentType of serverInfo, Data Center reports Server and older servers omit it
+    pub fn from_server_info(deployment_type: Option<&str>) -> Self {
+        match deployment_type {
+            Some(deployment_type) if deployment_type.eq_ignore_ascii_case("cloud") => {
+                DeploymentType::Cloud
+            }
+            _ => DeploymentType::DataCenter,
+        }
+    }
+}
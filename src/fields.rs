@@ -0,0 +1,468 @@
+//! Typed custom fields
+//!
+//! With the `derive` feature, `#[derive(JiraFields)]` maps struct fields to field ids or names.
+//!
+//! ```rust
+//! # #[cfg(feature = "derive")]
+//! # {
+//! use jira_issue_api::fields::JiraFields;
+//!
+//! #[derive(JiraFields)]
+//! struct Estimate {
+//!     summary: Option<String>,
+//!     #[jira(field = "customfield_10016")]
+//!     story_points: Option<f64>,
+//!     /// Resolved through Issue.names, query with expand=names
+//!     #[jira(name = "Team")]
+//!     team: Option<serde_json::Value>,
+//! }
+//! # }
+//! ```
+//!
+//! Field ids and names are translated with the FieldRegistry of JiraAPIClient::field_registry,
+//! which reuses get_fields for JiraClientConfig::field_cache_ttl and can be persisted between runs.
+//!
+//! ```rust,no_run
+//! # async fn story_points() -> Result<(), jira_issue_api::JiraClientError> {
+//! use jira_issue_api::fields::FileFieldCache;
+//! use jira_issue_api::{Credential, JiraAPIClient, JiraClientConfig};
+//! use std::sync::Arc;
+//!
+//! let cfg = JiraClientConfig {
+//!     credential: Credential::PersonalAccessToken("xxxxxxx".to_string()),
+//!     url: "https://jira.example.com".to_string(),
+//!     field_cache_store: Some(Arc::new(FileFieldCache::new("jira-fields.json"))),
+//!     ..Default::default()
+//! };
+//! let client = JiraAPIClient::new(&cfg)?;
+//!
+//! let fields = client.field_registry().await?;
+//! assert_eq!(Some("customfield_10016"), fields.id("Story Points"));
+//! # Ok(())
+//! # }
+//! ```
+use crate::{
+    models::{Field, Issue},
+    JiraClientError,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Formatter},
+    io::ErrorKind,
+    path::PathBuf,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+#[cfg(feature = "derive")]
+pub use jira_issue_api_derive::JiraFields;
+
+pub trait JiraFields: Sized {
+    /// Fields mapped by name are resolved through issue.names, query with expand=names.
+    fn from_issue(issue: &Issue) -> Result<Self, JiraClientError>;
+
+    /// {"fields": {...}} body for editing an issue, None fields are left out unless marked clear.
+    /// names: field id -> name, as in Issue.names, used for fields mapped by name.
+    fn to_edit_body_with_names(
+        &self,
+        names: &HashMap<String, String>,
+    ) -> Result<Value, JiraClientError>;
+
+    /// {"fields": {...}} body for editing an issue, fails for set fields mapped by name.
+    fn to_edit_body(&self) -> Result<Value, JiraClientError> {
+        self.to_edit_body_with_names(&HashMap::new())
+    }
+}
+
+/// Fields of an instance by id and by name
+#[derive(Debug, Clone, Default)]
+pub struct FieldRegistry {
+    fields: Vec<Field>,
+    by_id: HashMap<String, usize>,
+    /// Custom field names are not unique, a name can map to several fields
+    by_name: HashMap<String, Vec<usize>>,
+}
+
+impl FieldRegistry {
+    pub fn new(fields: Vec<Field>) -> Self {
+        let mut by_id = HashMap::new();
+        let mut by_name: HashMap<String, Vec<usize>> = HashMap::new();
+        for (i, field) in fields.iter().enumerate() {
+            by_id.insert(field.id.clone(), i);
+            by_name.entry(field.name.clone()).or_default().push(i);
+        }
+        FieldRegistry {
+            fields,
+            by_id,
+            by_name,
+        }
+    }
+
+    fn unique_name(&self, name: &str) -> Option<usize> {
+        match self.by_name.get(name).map(Vec::as_slice) {
+            Some(&[i]) => Some(i),
+            _ => None,
+        }
+    }
+
+    /// Field by id, or by name if no field has the id and the name is unique
+    pub fn field(&self, id_or_name: &str) -> Option<&Field> {
+        self.by_id
+            .get(id_or_name)
+            .copied()
+            .or_else(|| self.unique_name(id_or_name))
+            .map(|i| &self.fields[i])
+    }
+
+    /// Field id of a name, e.g. "Story Points" -> "customfield_10016".
+    /// None if several fields share the name, see ids
+    pub fn id(&self, name: &str) -> Option<&str> {
+        self.unique_name(name).map(|i| self.fields[i].id.as_str())
+    }
+
+    /// Field ids of every field with the name
+    pub fn ids(&self, name: &str) -> Vec<&str> {
+        self.by_name
+            .get(name)
+            .into_iter()
+            .flatten()
+            .map(|&i| self.fields[i].id.as_str())
+            .collect()
+    }
+
+    /// Field name of an id, e.g. "customfield_10016" -> "Story Points"
+    pub fn name(&self, id: &str) -> Option<&str> {
+        self.by_id.get(id).map(|&i| self.fields[i].name.as_str())
+    }
+
+    pub fn fields(&self) -> &[Field] {
+        &self.fields
+    }
+
+    /// Field id -> name, as in Issue.names, e.g. for JiraFields::to_edit_body_with_names
+    pub fn names(&self) -> HashMap<String, String> {
+        self.fields
+            .iter()
+            .map(|field| (field.id.clone(), field.name.clone()))
+            .collect()
+    }
+}
+
+/// Fields as stored by a FieldCacheStore
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct CachedFields {
+    /// Seconds since the unix epoch
+    pub fetched_at: u64,
+    pub fields: Vec<Field>,
+}
+
+impl CachedFields {
+    pub fn new(fetched_at: SystemTime, fields: Vec<Field>) -> Self {
+        CachedFields {
+            fetched_at: fetched_at
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            fields,
+        }
+    }
+
+    pub fn fetched_at(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(self.fetched_at)
+    }
+}
+
+/// Persists the fields of one instance between runs, see FileFieldCache.
+/// The client calls load and store on a blocking thread, so they may do file I/O.
+pub trait FieldCacheStore: Send + Sync {
+    /// None if nothing is stored, expired fields are ignored by the client
+    fn load(&self) -> Result<Option<CachedFields>, JiraClientError>;
+    fn store(&self, fields: &CachedFields) -> Result<(), JiraClientError>;
+
+    /// Called when load or store fails, the client fetches or keeps the fields regardless
+    fn on_error(&self, _error: JiraClientError) {}
+}
+
+impl Debug for dyn FieldCacheStore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("FieldCacheStore")
+    }
+}
+
+/// JSON file, use a file per instance. An unreadable file is treated as empty
+#[derive(Debug, Clone)]
+pub struct FileFieldCache {
+    path: PathBuf,
+}
+
+impl FileFieldCache {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        FileFieldCache { path: path.into() }
+    }
+
+    fn error(&self, action: &str, error: impl std::fmt::Display) -> JiraClientError {
+        JiraClientError::UnknownError(format!(
+            "Unable to {} field cache {}: {}",
+            action,
+            self.path.display(),
+            error
+        ))
+    }
+}
+
+impl FieldCacheStore for FileFieldCache {
+    fn load(&self) -> Result<Option<CachedFields>, JiraClientError> {
+        match std::fs::read(&self.path) {
+            Ok(json) => Ok(serde_json::from_slice(&json).ok()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(self.error("read", e)),
+        }
+    }
+
+    /// Written to a temporary file first, so concurrent readers never see a partial file
+    fn store(&self, fields: &CachedFields) -> Result<(), JiraClientError> {
+        let json = serde_json::to_vec(fields).map_err(|e| self.error("encode", e))?;
+        let mut tmp = self.path.clone().into_os_string();
+        tmp.push(".tmp");
+        std::fs::write(&tmp, json).map_err(|e| self.error("write", e))?;
+        std::fs::rename(&tmp, &self.path).map_err(|e| self.error("write", e))
+    }
+}
+
+/// Used by the derive macro
+#[doc(hidden)]
+pub mod __private {
+    use crate::{models::Issue, JiraClientError};
+    use serde::{de::DeserializeOwned, Serialize};
+    use std::collections::HashMap;
+
+    pub use serde_json::{Map, Value};
+
+    pub fn issue_fields(issue: &Issue) -> Result<Value, JiraClientError> {
+        serde_json::to_value(&issue.fields)
+            .map_err(|e| JiraClientError::TryFromError(format!("Unable to read fields: {}", e)))
+    }
+
+    /// Fails if the name is missing or shared by several fields, map those by id instead
+    pub fn field_id(
+        names: Option<&HashMap<String, String>>,
+        name: &str,
+    ) -> Result<String, JiraClientError> {
+        let mut ids = names
+            .into_iter()
+            .flatten()
+            .filter(|(_, n)| n.as_str() == name)
+            .map(|(id, _)| id.clone())
+            .collect::<Vec<_>>();
+        ids.sort();
+        match ids.len() {
+            0 => Err(JiraClientError::TryFromError(format!(
+                "Field name '{}' not found in names",
+                name
+            ))),
+            1 => Ok(ids.remove(0)),
+            _ => Err(JiraClientError::TryFromError(format!(
+                "Field name '{}' is ambiguous, map one of {} by field id",
+                name,
+                ids.join(", ")
+            ))),
+        }
+    }
+
+    /// Missing fields deserialize from null, so Option fields become None
+    pub fn get_field<T: DeserializeOwned>(fields: &Value, id: &str) -> Result<T, JiraClientError> {
+        let value = fields.get(id).cloned().unwrap_or(Value::Null);
+        serde_json::from_value(value)
+            .map_err(|e| JiraClientError::TryFromError(format!("Field '{}': {}", id, e)))
+    }
+
+    pub fn to_value<T: Serialize>(value: &T) -> Result<Value, JiraClientError> {
+        serde_json::to_value(value)
+            .map_err(|e| JiraClientError::JiraRequestBodyError(e.to_string()))
+    }
+
+    pub fn edit_body(fields: Map<String, Value>) -> Value {
+        let mut body = Map::new();
+        body.insert("fields".to_string(), Value::Object(fields));
+        Value::Object(body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "derive")]
+    use crate::models::{IssueFields, IssueKey};
+
+    #[cfg(feature = "derive")]
+    #[derive(JiraFields, Debug, PartialEq)]
+    struct Estimate {
+        summary: Option<String>,
+        #[jira(field = "customfield_10016")]
+        story_points: Option<f64>,
+        #[jira(name = "Team")]
+        team: Option<String>,
+    }
+
+    #[cfg(feature = "derive")]
+    fn issue() -> Issue {
+        let fields = serde_json::from_value::<IssueFields>(serde_json::json!({
+            "summary": "Summary",
+            "customfield_10016": 3.0,
+            "customfield_10020": "Platform",
+        }))
+        .unwrap();
+
+        Issue {
+            expand: None,
+            fields,
+            id: String::from("10000"),
+            key: IssueKey::try_from(String::from("JB-1")).unwrap(),
+            self_ref: String::new(),
+            names: Some(HashMap::from([(
+                String::from("customfield_10020"),
+                String::from("Team"),
+            )])),
+            changelog: None,
+        }
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn derive_from_issue_by_id_and_name() -> Result<(), JiraClientError> {
+        let estimate = Estimate::from_issue(&issue())?;
+        assert_eq!(
+            Estimate {
+                summary: Some(String::from("Summary")),
+                story_points: Some(3.0),
+                team: Some(String::from("Platform")),
+            },
+            estimate
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn derive_to_edit_body_resolves_names() -> Result<(), JiraClientError> {
+        let issue = issue();
+        let estimate = Estimate::from_issue(&issue)?;
+
+        assert!(estimate.to_edit_body().is_err());
+        assert_eq!(
+            serde_json::json!({"fields": {
+                "summary": "Summary",
+                "customfield_10016": 3.0,
+                "customfield_10020": "Platform",
+            }}),
+            estimate.to_edit_body_with_names(issue.names.as_ref().unwrap())?
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn derive_to_edit_body_skips_none() -> Result<(), JiraClientError> {
+        #[derive(JiraFields)]
+        struct Edit {
+            summary: Option<String>,
+            #[jira(name = "Team")]
+            team: Option<String>,
+            #[jira(field = "duedate", clear)]
+            due_date: Option<String>,
+        }
+
+        let edit = Edit {
+            summary: Some(String::from("Summary")),
+            team: None,
+            due_date: None,
+        };
+        // None fields are not resolved, so unknown names do not fail
+        assert_eq!(
+            serde_json::json!({"fields": {"summary": "Summary", "duedate": null}}),
+            edit.to_edit_body()?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn field_id_rejects_ambiguous_names() {
+        use super::__private::field_id;
+
+        let names = HashMap::from([
+            (String::from("customfield_10020"), String::from("Team")),
+            (
+                String::from("customfield_10016"),
+                String::from("Story Points"),
+            ),
+            (String::from("customfield_10030"), String::from("Team")),
+        ]);
+        assert_eq!(
+            "customfield_10016",
+            field_id(Some(&names), "Story Points").unwrap()
+        );
+        assert!(matches!(
+            field_id(Some(&names), "Team"),
+            Err(JiraClientError::TryFromError(message))
+                if message.contains("customfield_10020, customfield_10030")
+        ));
+        assert!(field_id(None, "Team").is_err());
+    }
+
+    fn field(id: &str, name: &str) -> Field {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "name": name,
+            "custom": id.starts_with("customfield_"),
+            "orderable": true,
+            "navigable": true,
+            "searchable": true,
+            "clauseNames": [],
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn registry_translates_ids_and_names() {
+        let registry = FieldRegistry::new(vec![
+            field("summary", "Summary"),
+            field("customfield_10016", "Story Points"),
+            field("customfield_10020", "Story Points"),
+        ]);
+
+        assert_eq!(Some("summary"), registry.id("Summary"));
+        assert_eq!(None, registry.id("Story Points"));
+        assert!(registry.field("Story Points").is_none());
+        assert_eq!(
+            vec!["customfield_10016", "customfield_10020"],
+            registry.ids("Story Points")
+        );
+        assert_eq!(Some("Story Points"), registry.name("customfield_10020"));
+        assert_eq!(
+            Some("summary"),
+            registry.field("Summary").map(|field| field.id.as_str())
+        );
+        assert_eq!(None, registry.id("Team"));
+        assert_eq!(3, registry.names().len());
+    }
+
+    #[test]
+    fn file_cache_round_trip() -> Result<(), JiraClientError> {
+        let path = std::env::temp_dir().join(format!("jira-fields-{}.json", std::process::id()));
+        let cache = FileFieldCache::new(&path);
+        assert!(cache.load()?.is_none());
+
+        let fetched_at = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        cache.store(&CachedFields::new(
+            fetched_at,
+            vec![field("customfield_10016", "Story Points")],
+        ))?;
+        let cached = cache.load()?.unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(fetched_at, cached.fetched_at());
+        assert_eq!("Story Points", cached.fields[0].name);
+        Ok(())
+    }
+}
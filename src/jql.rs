@@ -0,0 +1,862 @@
+use crate::JiraClientError;
+use regex::Regex;
+use std::{
+    fmt::{Display, Error, Formatter},
+    sync::OnceLock,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+impl Display for SortOrder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match self {
+            SortOrder::Asc => write!(f, "ASC"),
+            SortOrder::Desc => write!(f, "DESC"),
+        }
+    }
+}
+
+/// JQL query, use to_string() when passing it to query_issues.
+///
+/// ```rust
+/// use jira_issue_api::jql::{Jql, SortOrder};
+///
+/// let jql = Jql::new("project = JB")
+///     .order_by("priority", SortOrder::Desc)
+///     .order_by("created", SortOrder::Asc);
+///
+/// assert_eq!("project = JB ORDER BY priority DESC, created ASC", jql.to_string());
+/// ```
+///
+/// Clauses built from fields quote and escape values, so user input can not change the query.
+///
+/// ```rust
+/// use jira_issue_api::jql::{Jql, JqlFunction, SortOrder};
+///
+/// let jql = Jql::project("JB")
+///     .and(Jql::status_in(["Open", "In Progress"]))
+///     .and(Jql::field("Story Points").gt(3).or(Jql::field("assignee").eq(JqlFunction::CurrentUser)))
+///     .and(Jql::text("say \"hi\""))
+///     .order_by("created", SortOrder::Desc);
+///
+/// assert_eq!(
+///     r#"project = "JB" AND status in ("Open", "In Progress") AND ("Story Points" > 3 OR assignee = currentUser()) AND text ~ "say \"hi\"" ORDER BY created DESC"#,
+///     jql.to_string()
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Jql {
+    clause: String,
+    precedence: Precedence,
+    order_by: Vec<(String, SortOrder)>,
+}
+
+/// Top level operator of a clause, decides when it must be parenthesized
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum Precedence {
+    /// Raw clauses from Jql::new are always parenthesized when combined
+    #[default]
+    Raw,
+    Atom,
+    And,
+    Or,
+}
+
+impl Jql {
+    pub fn new(clause: impl Into<String>) -> Jql {
+        Jql {
+            clause: clause.into(),
+            precedence: Precedence::Raw,
+            order_by: Vec::new(),
+        }
+    }
+
+    /// Start a clause on a field by name or id, e.g. status, "Story Points" or cf[10016]
+    pub fn field(name: impl Into<String>) -> JqlField {
+        JqlField(name.into())
+    }
+
+    pub fn project(key: impl Into<JqlValue>) -> Jql {
+        Jql::field("project").eq(key)
+    }
+
+    pub fn status_in<V: Into<JqlValue>>(statuses: impl IntoIterator<Item = V>) -> Jql {
+        Jql::field("status").is_in(statuses)
+    }
+
+    pub fn assignee(user: impl Into<JqlValue>) -> Jql {
+        Jql::field("assignee").eq(user)
+    }
+
+    pub fn issue_type(issue_type: impl Into<JqlValue>) -> Jql {
+        Jql::field("issuetype").eq(issue_type)
+    }
+
+    /// Full text search across summary, description and comments
+    pub fn text(text: &str) -> Jql {
+        Jql::field("text").contains(text)
+    }
+
+    pub fn and(self, other: Jql) -> Jql {
+        self.combine(other, Precedence::And, "AND")
+    }
+
+    pub fn or(self, other: Jql) -> Jql {
+        self.combine(other, Precedence::Or, "OR")
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(self) -> Jql {
+        Jql {
+            clause: format!("NOT {}", self.operand(Precedence::Atom)),
+            precedence: Precedence::Atom,
+            order_by: self.order_by,
+        }
+    }
+
+    /// Chained calls append, the first field takes precedence.
+    /// The field is quoted like in the clause builders, e.g. "Story Points".
+    pub fn order_by(mut self, field: impl Into<String>, order: SortOrder) -> Jql {
+        self.order_by.push((quote_field(&field.into()), order));
+        self
+    }
+
+    fn atom(clause: String) -> Jql {
+        Jql {
+            clause,
+            precedence: Precedence::Atom,
+            order_by: Vec::new(),
+        }
+    }
+
+    /// Clause as an operand of op, parenthesized unless it binds at least as tight
+    fn operand(&self, op: Precedence) -> String {
+        match (self.precedence, op) {
+            (Precedence::Atom, _) | (Precedence::And, Precedence::And) => self.clause.clone(),
+            (Precedence::Or, Precedence::Or) => self.clause.clone(),
+            _ => format!("({})", self.clause),
+        }
+    }
+
+    fn combine(mut self, other: Jql, op: Precedence, keyword: &str) -> Jql {
+        // An empty clause matches everything, so it is dropped
+        if other.clause.is_empty() {
+            self.order_by.extend(other.order_by);
+            return self;
+        }
+        if self.clause.is_empty() {
+            let mut other = other;
+            self.order_by.extend(other.order_by);
+            other.order_by = self.order_by;
+            return other;
+        }
+
+        let clause = format!("{} {} {}", self.operand(op), keyword, other.operand(op));
+        self.order_by.extend(other.order_by);
+        Jql {
+            clause,
+            precedence: op,
+            order_by: self.order_by,
+        }
+    }
+}
+
+/// Field on the left hand side of a clause, see Jql::field
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JqlField(String);
+
+impl JqlField {
+    fn clause(self, operator: &str, value: impl Display) -> Jql {
+        Jql::atom(format!("{} {} {}", quote_field(&self.0), operator, value))
+    }
+
+    pub fn eq(self, value: impl Into<JqlValue>) -> Jql {
+        self.clause("=", value.into())
+    }
+
+    pub fn not_eq(self, value: impl Into<JqlValue>) -> Jql {
+        self.clause("!=", value.into())
+    }
+
+    pub fn gt(self, value: impl Into<JqlValue>) -> Jql {
+        self.clause(">", value.into())
+    }
+
+    pub fn gte(self, value: impl Into<JqlValue>) -> Jql {
+        self.clause(">=", value.into())
+    }
+
+    pub fn lt(self, value: impl Into<JqlValue>) -> Jql {
+        self.clause("<", value.into())
+    }
+
+    pub fn lte(self, value: impl Into<JqlValue>) -> Jql {
+        self.clause("<=", value.into())
+    }
+
+    /// Jira rejects an empty list
+    pub fn is_in<V: Into<JqlValue>>(self, values: impl IntoIterator<Item = V>) -> Jql {
+        self.clause("in", JqlList::from_iter(values))
+    }
+
+    /// Jira rejects an empty list
+    pub fn not_in<V: Into<JqlValue>>(self, values: impl IntoIterator<Item = V>) -> Jql {
+        self.clause("not in", JqlList::from_iter(values))
+    }
+
+    /// Text search, only valid on text fields
+    pub fn contains(self, text: &str) -> Jql {
+        self.clause("~", JqlValue::from(text))
+    }
+
+    pub fn not_contains(self, text: &str) -> Jql {
+        self.clause("!~", JqlValue::from(text))
+    }
+
+    pub fn is_empty(self) -> Jql {
+        self.clause("is", "EMPTY")
+    }
+
+    pub fn is_not_empty(self) -> Jql {
+        self.clause("is not", "EMPTY")
+    }
+}
+
+/// Value on the right hand side of a clause, strings are always quoted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JqlValue {
+    String(String),
+    Number(i64),
+    Function(JqlFunction),
+}
+
+impl From<&str> for JqlValue {
+    fn from(value: &str) -> Self {
+        JqlValue::String(value.to_string())
+    }
+}
+
+impl From<String> for JqlValue {
+    fn from(value: String) -> Self {
+        JqlValue::String(value)
+    }
+}
+
+impl From<&String> for JqlValue {
+    fn from(value: &String) -> Self {
+        JqlValue::String(value.clone())
+    }
+}
+
+impl From<i64> for JqlValue {
+    fn from(value: i64) -> Self {
+        JqlValue::Number(value)
+    }
+}
+
+impl From<i32> for JqlValue {
+    fn from(value: i32) -> Self {
+        JqlValue::Number(value.into())
+    }
+}
+
+impl From<u32> for JqlValue {
+    fn from(value: u32) -> Self {
+        JqlValue::Number(value.into())
+    }
+}
+
+impl From<JqlFunction> for JqlValue {
+    fn from(value: JqlFunction) -> Self {
+        JqlValue::Function(value)
+    }
+}
+
+impl Display for JqlValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        match self {
+            JqlValue::String(value) => write!(f, "{}", quote(value)),
+            JqlValue::Number(value) => write!(f, "{}", value),
+            JqlValue::Function(function) => write!(f, "{}", function),
+        }
+    }
+}
+
+struct JqlList(Vec<JqlValue>);
+
+impl<V: Into<JqlValue>> FromIterator<V> for JqlList {
+    fn from_iter<I: IntoIterator<Item = V>>(iter: I) -> Self {
+        JqlList(iter.into_iter().map(Into::into).collect())
+    }
+}
+
+impl Display for JqlList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "(")?;
+        for (i, value) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", value)?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Double quoted JQL string
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Reserved words and keywords of JQL, which must be quoted as field names
+/// https://support.atlassian.com/jira-software-cloud/docs/use-advanced-search-with-jira-query-language-jql/
+const RESERVED_WORDS: &[&str] = &[
+    "a",
+    "abort",
+    "access",
+    "add",
+    "after",
+    "alias",
+    "all",
+    "alter",
+    "an",
+    "and",
+    "any",
+    "are",
+    "as",
+    "asc",
+    "at",
+    "audit",
+    "avg",
+    "before",
+    "begin",
+    "between",
+    "boolean",
+    "break",
+    "by",
+    "byte",
+    "catch",
+    "cf",
+    "changed",
+    "char",
+    "character",
+    "check",
+    "checkpoint",
+    "collate",
+    "collation",
+    "column",
+    "commit",
+    "connect",
+    "continue",
+    "count",
+    "create",
+    "current",
+    "date",
+    "decimal",
+    "declare",
+    "decrement",
+    "default",
+    "defaults",
+    "define",
+    "delete",
+    "delimiter",
+    "desc",
+    "difference",
+    "distinct",
+    "divide",
+    "do",
+    "double",
+    "drop",
+    "during",
+    "else",
+    "empty",
+    "encoding",
+    "end",
+    "equals",
+    "escape",
+    "exclusive",
+    "exec",
+    "execute",
+    "exists",
+    "explain",
+    "false",
+    "fetch",
+    "field",
+    "file",
+    "first",
+    "float",
+    "for",
+    "from",
+    "function",
+    "go",
+    "goto",
+    "grant",
+    "greater",
+    "group",
+    "having",
+    "identified",
+    "if",
+    "immediate",
+    "in",
+    "increment",
+    "index",
+    "initial",
+    "inner",
+    "inout",
+    "input",
+    "insert",
+    "int",
+    "integer",
+    "intersect",
+    "intersection",
+    "into",
+    "is",
+    "isempty",
+    "isnull",
+    "join",
+    "last",
+    "left",
+    "less",
+    "like",
+    "limit",
+    "lock",
+    "long",
+    "max",
+    "min",
+    "minus",
+    "mode",
+    "modify",
+    "modulo",
+    "more",
+    "multiply",
+    "next",
+    "noaudit",
+    "not",
+    "notin",
+    "nowait",
+    "null",
+    "number",
+    "object",
+    "of",
+    "on",
+    "option",
+    "or",
+    "order",
+    "outer",
+    "output",
+    "power",
+    "previous",
+    "prior",
+    "privileges",
+    "public",
+    "raise",
+    "raw",
+    "remainder",
+    "rename",
+    "resource",
+    "return",
+    "returns",
+    "revoke",
+    "right",
+    "row",
+    "rowid",
+    "rownum",
+    "rows",
+    "select",
+    "session",
+    "set",
+    "share",
+    "size",
+    "sqrt",
+    "start",
+    "strict",
+    "string",
+    "subtract",
+    "sum",
+    "synonym",
+    "table",
+    "then",
+    "to",
+    "trans",
+    "transaction",
+    "trigger",
+    "true",
+    "uid",
+    "union",
+    "unique",
+    "update",
+    "user",
+    "validate",
+    "values",
+    "view",
+    "was",
+    "when",
+    "whenever",
+    "where",
+    "while",
+    "with",
+];
+
+static FIELD_RE: OnceLock<Regex> = OnceLock::new();
+
+/// Field names with spaces or reserved words are quoted, cf[10016] is kept as is
+fn quote_field(name: &str) -> String {
+    let field_re = FIELD_RE.get_or_init(|| {
+        Regex::new(r"^([A-Za-z][A-Za-z0-9_.]*|cf\[[0-9]+\])$").expect("Unable to compile FIELD_RE")
+    });
+
+    if field_re.is_match(name) && !RESERVED_WORDS.contains(&name.to_lowercase().as_str()) {
+        name.to_string()
+    } else {
+        quote(name)
+    }
+}
+
+impl Display for Jql {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "{}", self.clause)?;
+
+        for (i, (field, order)) in self.order_by.iter().enumerate() {
+            match (i, self.clause.is_empty()) {
+                (0, true) => write!(f, "ORDER BY {} {}", field, order)?,
+                (0, false) => write!(f, " ORDER BY {} {}", field, order)?,
+                _ => write!(f, ", {} {}", field, order)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl From<Jql> for String {
+    fn from(val: Jql) -> Self {
+        val.to_string()
+    }
+}
+
+/// Result of validating a query with validate_jql
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JqlValidation {
+    pub query: String,
+    pub errors: Vec<JqlSyntaxError>,
+    /// Cloud only, e.g. deprecated fields
+    pub warnings: Vec<String>,
+}
+
+impl JqlValidation {
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Position is parsed from the message, e.g. "... (line 1, character 14)"
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JqlSyntaxError {
+    pub message: String,
+    pub line: Option<u32>,
+    pub character: Option<u32>,
+}
+
+static POSITION_RE: OnceLock<Regex> = OnceLock::new();
+
+impl From<String> for JqlSyntaxError {
+    fn from(message: String) -> Self {
+        let position_re = POSITION_RE.get_or_init(|| {
+            Regex::new(r"line ([0-9]+), character ([0-9]+)").expect("Unable to compile POSITION_RE")
+        });
+
+        let position = position_re.captures(&message).map(|captures| {
+            (
+                captures[1].parse::<u32>().ok(),
+                captures[2].parse::<u32>().ok(),
+            )
+        });
+        let (line, character) = position.unwrap_or_default();
+        JqlSyntaxError {
+            message,
+            line,
+            character,
+        }
+    }
+}
+
+impl Display for JqlSyntaxError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "{}", self.message)
+    }
+}
+
+/// Relative date offset accepted by JQL date functions, e.g. "-7d", "+1w", "2M".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelativeDate(String);
+
+impl Display for RelativeDate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "{}", self.0)
+    }
+}
+
+static RELATIVE_DATE_RE: OnceLock<Regex> = OnceLock::new();
+
+impl TryFrom<&str> for RelativeDate {
+    type Error = JiraClientError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let relative_date_re = RELATIVE_DATE_RE.get_or_init(|| {
+            Regex::new(r"^[+-]?[0-9]+[yMwdhm]$").expect("Unable to compile RELATIVE_DATE_RE")
+        });
+
+        if !relative_date_re.is_match(value) {
+            return Err(JiraClientError::JqlError(format!(
+                "Malformed relative date: '{}', expected (+/-)nn(y|M|w|d|h|m)",
+                value
+            )));
+        }
+
+        Ok(RelativeDate(value.to_string()))
+    }
+}
+
+/// Common JQL functions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JqlFunction {
+    CurrentUser,
+    MembersOf(String),
+    OpenSprints,
+    ClosedSprints,
+    FutureSprints,
+    StartOfDay(Option<RelativeDate>),
+    EndOfDay(Option<RelativeDate>),
+    StartOfWeek(Option<RelativeDate>),
+    EndOfWeek(Option<RelativeDate>),
+    StartOfMonth(Option<RelativeDate>),
+    EndOfMonth(Option<RelativeDate>),
+    StartOfYear(Option<RelativeDate>),
+    EndOfYear(Option<RelativeDate>),
+}
+
+impl JqlFunction {
+    pub fn members_of(group: impl Into<String>) -> JqlFunction {
+        JqlFunction::MembersOf(group.into())
+    }
+
+    pub fn start_of_day(offset: &str) -> Result<JqlFunction, JiraClientError> {
+        Ok(JqlFunction::StartOfDay(Some(RelativeDate::try_from(
+            offset,
+        )?)))
+    }
+
+    pub fn end_of_day(offset: &str) -> Result<JqlFunction, JiraClientError> {
+        Ok(JqlFunction::EndOfDay(Some(RelativeDate::try_from(offset)?)))
+    }
+
+    pub fn start_of_week(offset: &str) -> Result<JqlFunction, JiraClientError> {
+        Ok(JqlFunction::StartOfWeek(Some(RelativeDate::try_from(
+            offset,
+        )?)))
+    }
+
+    pub fn end_of_week(offset: &str) -> Result<JqlFunction, JiraClientError> {
+        Ok(JqlFunction::EndOfWeek(Some(RelativeDate::try_from(
+            offset,
+        )?)))
+    }
+
+    pub fn start_of_month(offset: &str) -> Result<JqlFunction, JiraClientError> {
+        Ok(JqlFunction::StartOfMonth(Some(RelativeDate::try_from(
+            offset,
+        )?)))
+    }
+
+    pub fn end_of_month(offset: &str) -> Result<JqlFunction, JiraClientError> {
+        Ok(JqlFunction::EndOfMonth(Some(RelativeDate::try_from(
+            offset,
+        )?)))
+    }
+
+    pub fn start_of_year(offset: &str) -> Result<JqlFunction, JiraClientError> {
+        Ok(JqlFunction::StartOfYear(Some(RelativeDate::try_from(
+            offset,
+        )?)))
+    }
+
+    pub fn end_of_year(offset: &str) -> Result<JqlFunction, JiraClientError> {
+        Ok(JqlFunction::EndOfYear(Some(RelativeDate::try_from(
+            offset,
+        )?)))
+    }
+}
+
+impl Display for JqlFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let (name, offset) = match self {
+            JqlFunction::CurrentUser => return write!(f, "currentUser()"),
+            JqlFunction::OpenSprints => return write!(f, "openSprints()"),
+            JqlFunction::ClosedSprints => return write!(f, "closedSprints()"),
+            JqlFunction::FutureSprints => return write!(f, "futureSprints()"),
+            JqlFunction::MembersOf(group) => return write!(f, "membersOf({})", quote(group)),
+            JqlFunction::StartOfDay(offset) => ("startOfDay", offset),
+            JqlFunction::EndOfDay(offset) => ("endOfDay", offset),
+            JqlFunction::StartOfWeek(offset) => ("startOfWeek", offset),
+            JqlFunction::EndOfWeek(offset) => ("endOfWeek", offset),
+            JqlFunction::StartOfMonth(offset) => ("startOfMonth", offset),
+            JqlFunction::EndOfMonth(offset) => ("endOfMonth", offset),
+            JqlFunction::StartOfYear(offset) => ("startOfYear", offset),
+            JqlFunction::EndOfYear(offset) => ("endOfYear", offset),
+        };
+
+        match offset {
+            Some(offset) => write!(f, "{}({})", name, offset),
+            None => write!(f, "{}()", name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn jql_order_by_chains_fields() {
+        let jql = Jql::new("assignee = currentUser()")
+            .order_by("updated", SortOrder::Desc)
+            .order_by("key", SortOrder::Asc);
+        assert_eq!(
+            "assignee = currentUser() ORDER BY updated DESC, key ASC",
+            jql.to_string()
+        );
+    }
+
+    #[test]
+    fn jql_order_by_without_clause() {
+        let jql = Jql::default().order_by("created", SortOrder::Asc);
+        assert_eq!("ORDER BY created ASC", jql.to_string());
+    }
+
+    #[test]
+    fn jql_quotes_reserved_field_names() {
+        for name in [
+            "Start", "size", "user", "Group", "select", "limit", "date", "with",
+        ] {
+            assert_eq!(
+                format!("\"{}\" = 1", name),
+                Jql::field(name).eq(1).to_string()
+            );
+        }
+        assert_eq!("status = 1", Jql::field("status").eq(1).to_string());
+    }
+
+    #[test]
+    fn jql_order_by_quotes_fields() {
+        let jql = Jql::default()
+            .order_by("Story Points", SortOrder::Desc)
+            .order_by("created DESC, x = 1 OR", SortOrder::Asc)
+            .order_by("cf[10016]", SortOrder::Asc);
+        assert_eq!(
+            r#"ORDER BY "Story Points" DESC, "created DESC, x = 1 OR" ASC, cf[10016] ASC"#,
+            jql.to_string()
+        );
+    }
+
+    #[test]
+    fn jql_builder_parenthesizes_by_precedence() {
+        let a = || Jql::field("x").eq(1);
+        let b = || Jql::field("y").eq(2);
+        let c = || Jql::field("z").eq(3);
+
+        assert_eq!(
+            "x = 1 AND y = 2 AND z = 3",
+            a().and(b()).and(c()).to_string()
+        );
+        assert_eq!(
+            "(x = 1 OR y = 2) AND z = 3",
+            a().or(b()).and(c()).to_string()
+        );
+        assert_eq!(
+            "x = 1 OR (y = 2 AND z = 3)",
+            a().or(b().and(c())).to_string()
+        );
+        assert_eq!("NOT (x = 1 OR y = 2)", a().or(b()).not().to_string());
+        assert_eq!(
+            "(project = JB OR project = JA) AND x = 1",
+            Jql::new("project = JB OR project = JA")
+                .and(a())
+                .to_string()
+        );
+        assert_eq!("x = 1", Jql::default().and(a()).to_string());
+    }
+
+    #[test]
+    fn jql_builder_quotes_fields_and_values() {
+        assert_eq!(
+            r#""Story Points" >= 3"#,
+            Jql::field("Story Points").gte(3).to_string()
+        );
+        assert_eq!(
+            "cf[10016] is EMPTY",
+            Jql::field("cf[10016]").is_empty().to_string()
+        );
+        assert_eq!(
+            r#""order" = "by""#,
+            Jql::field("order").eq("by").to_string()
+        );
+        assert_eq!(
+            r#"summary ~ "\" OR 1 = 1 \\""#,
+            Jql::field("summary").contains("\" OR 1 = 1 \\").to_string()
+        );
+        assert_eq!(
+            r#"labels not in ("a", "b")"#,
+            Jql::field("labels").not_in(["a", "b"]).to_string()
+        );
+        assert_eq!(
+            "assignee in (membersOf(\"devs\"), currentUser())",
+            Jql::field("assignee")
+                .is_in([JqlFunction::members_of("devs"), JqlFunction::CurrentUser])
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn jql_syntax_error_position() {
+        let error = JqlSyntaxError::from(
+            "Error in the JQL Query: Expecting either a value, list or function but got 'AND'. You must surround 'AND' in quotation marks to use it as a value. (line 1, character 14)"
+                .to_string(),
+        );
+        assert_eq!((Some(1), Some(14)), (error.line, error.character));
+
+        let error = JqlSyntaxError::from("Field 'x' does not exist".to_string());
+        assert_eq!((None, None), (error.line, error.character));
+    }
+
+    #[test]
+    fn relative_date_tryfrom_valid_offsets() {
+        for input in ["-7d", "+1w", "2M", "-1y", "30m", "-4h"] {
+            assert!(RelativeDate::try_from(input).is_ok(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn relative_date_tryfrom_invalid_offsets() {
+        for input in ["", "-d", "7", "-7x", "-7 d", "7d)"] {
+            assert!(RelativeDate::try_from(input).is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn jql_function_tostring() -> Result<(), JiraClientError> {
+        assert_eq!("currentUser()", JqlFunction::CurrentUser.to_string());
+        assert_eq!("openSprints()", JqlFunction::OpenSprints.to_string());
+        assert_eq!("startOfDay()", JqlFunction::StartOfDay(None).to_string());
+        assert_eq!(
+            "startOfDay(-7d)",
+            JqlFunction::start_of_day("-7d")?.to_string()
+        );
+        assert_eq!(
+            "membersOf(\"jira \\\"admins\\\"\")",
+            JqlFunction::members_of("jira \"admins\"").to_string()
+        );
+        Ok(())
+    }
+}
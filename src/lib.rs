@@ -1,4 +1,33 @@
+pub mod access;
+pub mod adf;
+pub mod agile;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod api;
+#[cfg(feature = "asap")]
+pub mod asap;
+pub mod assets;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+#[cfg(not(target_arch = "wasm32"))]
+mod cache;
 mod client;
+pub mod codegen;
+#[cfg(feature = "connect")]
+pub mod connect;
+pub mod deployment;
+pub mod fields;
+pub mod jql;
+pub mod metrics;
+#[cfg(all(feature = "mock", not(target_arch = "wasm32")))]
+pub mod mock;
 pub mod models;
+pub mod oauth;
+pub mod retry;
+pub mod session;
+mod stream;
+pub mod webhooks;
 
 pub use crate::client::*;
+
+// Lets derive macro output refer to ::jira_issue_api inside this crate
+extern crate self as jira_issue_api;
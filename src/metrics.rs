@@ -0,0 +1,150 @@
+//! Request metrics, e.g. for Prometheus dashboards of bots calling Jira at volume.
+//!
+//! The recorder is called once per request when the response headers are received or the request fails,
+//! after retries and reauthentication. Token refreshes and session logins are not recorded, nor is anything on wasm.
+//!
+//! ```rust
+//! use jira_issue_api::metrics::RequestMetric;
+//! use jira_issue_api::JiraClientConfig;
+//! use std::sync::Arc;
+//!
+//! let cfg = JiraClientConfig {
+//!     url: "https://jira.example.com".to_string(),
+//!     metrics: Some(Arc::new(|metric: &RequestMetric| {
+//!         // e.g. histogram!("jira_request_duration_seconds", "endpoint" => metric.endpoint.clone(), ...)
+//!         println!(
+//!             "{} {} {} {:?}",
+//!             metric.method,
+//!             metric.endpoint,
+//!             metric.status_class.as_str(),
+//!             metric.duration
+//!         );
+//!     })),
+//!     ..Default::default()
+//! };
+//! ```
+use crate::models::IssueKey;
+use reqwest::{Method, StatusCode, Url};
+use std::{
+    fmt::{Debug, Formatter},
+    time::Duration,
+};
+
+/// Receives a RequestMetric for every completed request, shared by clones of the client
+pub trait MetricsRecorder: Send + Sync {
+    fn record(&self, metric: &RequestMetric);
+}
+
+impl<F: Fn(&RequestMetric) + Send + Sync> MetricsRecorder for F {
+    fn record(&self, metric: &RequestMetric) {
+        self(metric)
+    }
+}
+
+impl Debug for dyn MetricsRecorder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("MetricsRecorder")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestMetric {
+    pub method: Method,
+    /// Path below rest/ with ids replaced, e.g. api/latest/issue/{key}/comment/{id}
+    pub endpoint: String,
+    /// None if the request failed without a response
+    pub status: Option<StatusCode>,
+    pub status_class: StatusClass,
+    /// Time to the response headers, including retries and their delays
+    pub duration: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatusClass {
+    Informational,
+    Success,
+    Redirection,
+    ClientError,
+    ServerError,
+    /// No response, e.g. connection errors and timeouts
+    Error,
+}
+
+impl StatusClass {
+    pub fn from_status(status: Option<StatusCode>) -> Self {
+        match status.map(|status| status.as_u16() / 100) {
+            Some(1) => StatusClass::Informational,
+            Some(2) => StatusClass::Success,
+            Some(3) => StatusClass::Redirection,
+            Some(4) => StatusClass::ClientError,
+            Some(5) => StatusClass::ServerError,
+            _ => StatusClass::Error,
+        }
+    }
+
+    /// Label value, e.g. 2xx
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StatusClass::Informational => "1xx",
+            StatusClass::Success => "2xx",
+            StatusClass::Redirection => "3xx",
+            StatusClass::ClientError => "4xx",
+            StatusClass::ServerError => "5xx",
+            StatusClass::Error => "error",
+        }
+    }
+}
+
+/// Path of the url relative to base, without rest/, with numeric ids and issue keys replaced
+/// to keep the number of distinct endpoints low.
+/// The two leading segments are kept as is, they name the API and its version, e.g. api/2.
+pub(crate) fn endpoint(url: &Url, base: &Url) -> String {
+    let path = url
+        .path()
+        .strip_prefix(base.path())
+        .unwrap_or(url.path().trim_start_matches('/'));
+    let path = path.strip_prefix("rest/").unwrap_or(path);
+
+    path.trim_end_matches('/')
+        .split('/')
+        .enumerate()
+        .map(|(i, segment)| match segment {
+            _ if i < 2 => segment,
+            _ if !segment.is_empty() && segment.bytes().all(|b| b.is_ascii_digit()) => "{id}",
+            _ if IssueKey::try_from(segment.to_string()).is_ok() => "{key}",
+            _ => segment,
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn endpoint_replaces_ids() -> Result<(), url::ParseError> {
+        let base = Url::parse("https://example.com/jira/")?;
+        assert_eq!(
+            "api/2/issue/{key}/comment/{id}",
+            endpoint(
+                &base.join("rest/api/2/issue/JB-12/comment/10001?expand=all")?,
+                &base
+            )
+        );
+        assert_eq!(
+            "agile/1.0/board/{id}/sprint",
+            endpoint(&base.join("rest/agile/1.0/board/42/sprint/")?, &base)
+        );
+        assert_eq!(
+            "api/latest/search",
+            endpoint(&base.join("rest/api/latest/search")?, &base)
+        );
+        assert_eq!(
+            StatusClass::ClientError,
+            StatusClass::from_status(Some(StatusCode::NOT_FOUND))
+        );
+        assert_eq!("error", StatusClass::from_status(None).as_str());
+        Ok(())
+    }
+}
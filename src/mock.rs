@@ -0,0 +1,340 @@
+//! In-memory fake of the [api](crate::api) traits, enabled with the `mock` feature.
+//!
+//! Serves canned issues, users and transitions, and records posted transitions.
+//! Every other method fails with JiraClientError::UnknownError.
+//!
+//! ```rust
+//! # async fn transition() -> Result<(), jira_issue_api::JiraClientError> {
+//! use jira_issue_api::api::{JiraApi, JiraApiWrite};
+//! use jira_issue_api::mock::MockJiraClient;
+//! use jira_issue_api::models::*;
+//!
+//! let issue: Issue = serde_json::from_str(
+//!     r#"{"id": "10000", "key": "JB-1", "self": "https://jira.example.com/rest/api/2/issue/10000", "fields": {}}"#,
+//! )
+//! .unwrap();
+//! let key = issue.key.clone();
+//! let done = Transition {
+//!     fields: Default::default(),
+//!     id: "31".to_string(),
+//!     name: "Done".to_string(),
+//! };
+//! let jira = MockJiraClient::new()
+//!     .with_issue(issue)
+//!     .with_transitions(&key, vec![done]);
+//!
+//! // Code under test
+//! let transition = PostTransitionBody {
+//!     transition: PostTransitionIdBody { id: "31".to_string() },
+//!     fields: None,
+//!     update: None,
+//! };
+//! jira.post_transition(&key, &transition).await?;
+//!
+//! assert_eq!(vec![(key, "31".to_string())], jira.posted_transitions());
+//! # Ok(())
+//! # }
+//! ```
+use crate::{
+    api::{JiraApi, JiraApiCloud, JiraApiCloudWrite, JiraApiWrite},
+    models::{
+        GetAssignableUserParams, GetTransitionsBody, Issue, IssueKey, PostTransitionBody,
+        Transition, User,
+    },
+    JiraClientError, RequestContext,
+};
+use reqwest::{Method, StatusCode, Url};
+use std::{
+    collections::BTreeMap,
+    future::Future,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+#[derive(Debug, Default)]
+struct MockState {
+    issues: BTreeMap<String, Issue>,
+    users: Vec<User>,
+    myself: Option<User>,
+    transitions: BTreeMap<String, Vec<Transition>>,
+    posted_transitions: Vec<(IssueKey, String)>,
+}
+
+/// Fake Jira, clones share their state
+#[derive(Debug, Clone, Default)]
+pub struct MockJiraClient {
+    state: Arc<Mutex<MockState>>,
+}
+
+impl MockJiraClient {
+    pub fn new() -> Self {
+        MockJiraClient::default()
+    }
+
+    pub fn with_issue(self, issue: Issue) -> Self {
+        self.lock().issues.insert(issue.key.to_string(), issue);
+        self
+    }
+
+    pub fn with_user(self, user: User) -> Self {
+        self.lock().users.push(user);
+        self
+    }
+
+    /// User returned by get_myself, also added to the users
+    pub fn with_myself(self, user: User) -> Self {
+        let mut state = self.lock();
+        state.myself = Some(user.clone());
+        state.users.push(user);
+        drop(state);
+        self
+    }
+
+    /// Transitions available for the issue
+    pub fn with_transitions(self, issue_key: &IssueKey, transitions: Vec<Transition>) -> Self {
+        self.lock()
+            .transitions
+            .insert(issue_key.to_string(), transitions);
+        self
+    }
+
+    /// Issue keys and transition ids posted with post_transition, oldest first
+    pub fn posted_transitions(&self) -> Vec<(IssueKey, String)> {
+        self.lock().posted_transitions.clone()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, MockState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn issue(&self, issue_key: &IssueKey) -> Result<Issue, JiraClientError> {
+        self.lock()
+            .issues
+            .get(&issue_key.to_string())
+            .cloned()
+            .ok_or_else(|| {
+                error(
+                    Method::GET,
+                    &format!("issue/{}", issue_key),
+                    StatusCode::NOT_FOUND,
+                    "Issue does not exist or you do not have permission to see it.",
+                )
+            })
+    }
+}
+
+/// ApiError as returned by Jira
+fn error(method: Method, path: &str, status: StatusCode, message: &str) -> JiraClientError {
+    let url = Url::parse("https://jira.mock/rest/api/latest/")
+        .and_then(|base| base.join(path))
+        .expect("mock url is valid");
+    JiraClientError::ApiError {
+        context: Box::new(RequestContext::new(method, &url, Some(status))),
+        status,
+        error_messages: vec![message.to_string()],
+        errors: BTreeMap::new(),
+    }
+}
+
+impl JiraApi for MockJiraClient {
+    fn get_issue(
+        &self,
+        issue_key: &IssueKey,
+        _expand_options: Option<&str>,
+    ) -> impl Future<Output = Result<Issue, JiraClientError>> + Send {
+        let issue = self.issue(issue_key);
+        async { issue }
+    }
+
+    fn get_issues(
+        &self,
+        issue_keys: &[IssueKey],
+        _expand_options: Option<&str>,
+    ) -> impl Future<Output = Vec<Result<Issue, JiraClientError>>> + Send {
+        let issues = issue_keys.iter().map(|key| self.issue(key)).collect();
+        async { issues }
+    }
+
+    fn get_transitions(
+        &self,
+        issue_key: &IssueKey,
+        _expand_options: Option<&str>,
+    ) -> impl Future<Output = Result<GetTransitionsBody, JiraClientError>> + Send {
+        let transitions = self.issue(issue_key).map(|_| GetTransitionsBody {
+            expand: "transitions".to_string(),
+            transitions: self
+                .lock()
+                .transitions
+                .get(&issue_key.to_string())
+                .cloned()
+                .unwrap_or_default(),
+        });
+        async { transitions }
+    }
+
+    fn get_myself(&self) -> impl Future<Output = Result<User, JiraClientError>> + Send {
+        let myself = self.lock().myself.clone().ok_or_else(|| {
+            error(
+                Method::GET,
+                "myself",
+                StatusCode::UNAUTHORIZED,
+                "You are not authenticated.",
+            )
+        });
+        async { myself }
+    }
+
+    fn get_user(&self, user: &str) -> impl Future<Output = Result<User, JiraClientError>> + Send {
+        let found = self
+            .lock()
+            .users
+            .iter()
+            .find(|candidate| candidate.id() == Some(user))
+            .cloned()
+            .ok_or_else(|| {
+                error(
+                    Method::GET,
+                    "user",
+                    StatusCode::NOT_FOUND,
+                    &format!("The user with the identifier '{}' does not exist.", user),
+                )
+            });
+        async { found }
+    }
+
+    /// Users whose display name contains the query, ignoring case
+    fn search_users(
+        &self,
+        query: &str,
+        start_at: u32,
+        max_results: Option<u32>,
+    ) -> impl Future<Output = Result<Vec<User>, JiraClientError>> + Send {
+        let query = query.to_lowercase();
+        let users = self
+            .lock()
+            .users
+            .iter()
+            .filter(|user| user.display_name.to_lowercase().contains(&query))
+            .skip(start_at as usize)
+            .take(max_results.unwrap_or(50) as usize)
+            .cloned()
+            .collect();
+        async { Ok(users) }
+    }
+
+    /// Every user is assignable
+    fn get_assignable_users(
+        &self,
+        _params: &GetAssignableUserParams,
+    ) -> impl Future<Output = Result<Vec<User>, JiraClientError>> + Send {
+        let users = self.lock().users.clone();
+        async { Ok(users) }
+    }
+}
+
+impl JiraApiWrite for MockJiraClient {
+    /// Records the transition if it is one of the issue's canned transitions
+    fn post_transition(
+        &self,
+        issue_key: &IssueKey,
+        transition: &PostTransitionBody,
+    ) -> impl Future<Output = Result<(), JiraClientError>> + Send {
+        let id = &transition.transition.id;
+        let result = self.issue(issue_key).and_then(|_| {
+            let mut state = self.lock();
+            let available = state
+                .transitions
+                .get(&issue_key.to_string())
+                .is_some_and(|transitions| transitions.iter().any(|t| &t.id == id));
+            if !available {
+                return Err(error(
+                    Method::POST,
+                    &format!("issue/{}/transitions", issue_key),
+                    StatusCode::BAD_REQUEST,
+                    &format!("Transition id '{}' is not valid for this issue.", id),
+                ));
+            }
+            state
+                .posted_transitions
+                .push((issue_key.clone(), id.clone()));
+            Ok(())
+        });
+        async { result }
+    }
+}
+
+impl JiraApiCloud for MockJiraClient {}
+
+impl JiraApiCloudWrite for MockJiraClient {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::PostTransitionIdBody;
+    use futures_util::FutureExt;
+
+    fn issue(key: &str) -> Issue {
+        serde_json::from_value(serde_json::json!({
+            "id": "10000",
+            "key": key,
+            "self": "https://jira.mock/rest/api/latest/issue/10000",
+            "fields": {}
+        }))
+        .unwrap()
+    }
+
+    fn transition(id: &str) -> Transition {
+        Transition {
+            fields: Default::default(),
+            id: id.to_string(),
+            name: "Done".to_string(),
+        }
+    }
+
+    #[test]
+    fn serves_canned_issues() {
+        let jira = MockJiraClient::new().with_issue(issue("JB-1"));
+        let key = IssueKey::try_from("JB-1".to_string()).unwrap();
+        let missing = IssueKey::try_from("JB-2".to_string()).unwrap();
+
+        let found = jira.get_issue(&key, None).now_or_never().unwrap().unwrap();
+        assert_eq!(key, found.key);
+        assert!(matches!(
+            jira.get_issue(&missing, None).now_or_never().unwrap(),
+            Err(JiraClientError::ApiError {
+                status: StatusCode::NOT_FOUND,
+                ..
+            })
+        ));
+        assert!(matches!(
+            jira.get_fields().now_or_never().unwrap(),
+            Err(JiraClientError::UnknownError(_))
+        ));
+    }
+
+    #[test]
+    fn records_valid_transitions() {
+        let key = IssueKey::try_from("JB-1".to_string()).unwrap();
+        let jira = MockJiraClient::new()
+            .with_issue(issue("JB-1"))
+            .with_transitions(&key, vec![transition("31")]);
+        let body = |id: &str| PostTransitionBody {
+            transition: PostTransitionIdBody { id: id.to_string() },
+            fields: None,
+            update: None,
+        };
+
+        assert!(jira
+            .post_transition(&key, &body("41"))
+            .now_or_never()
+            .unwrap()
+            .is_err());
+        jira.post_transition(&key, &body("31"))
+            .now_or_never()
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            vec![(key.clone(), "31".to_string())],
+            jira.posted_transitions()
+        );
+    }
+}
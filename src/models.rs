@@ -1,3 +1,4 @@
+use crate::adf::RichText;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -8,74 +9,169 @@ use std::{
     sync::OnceLock,
 };
 
-#[cfg(not(feature = "cloud"))]
-mod versioned {
-    use super::*;
+/// Cloud identifies users by account_id, Data Center by name
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct User {
+    pub active: bool,
+    pub display_name: String,
+    /// Cloud only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<String>,
+    /// Data Center only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Hidden by profile visibility settings on Cloud
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_address: Option<String>,
+    /// Data Center only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted: Option<bool>,
+    /// Set for the current user and admins, e.g. Europe/Copenhagen
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+}
 
-    #[derive(Deserialize, Serialize, Debug, Clone)]
-    #[serde(rename_all = "camelCase")]
-    pub struct User {
-        pub active: bool,
-        pub display_name: String,
-        pub deleted: Option<bool>,
-        pub name: String,
+impl User {
+    /// account_id on Cloud, name on Data Center
+    pub fn id(&self) -> Option<&str> {
+        self.account_id.as_deref().or(self.name.as_deref())
     }
+}
 
-    #[derive(Serialize, Debug, Clone)]
-    pub struct PostAssignBody {
-        pub name: String,
-    }
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserPickerUser {
+    /// Cloud only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<String>,
+    /// Cloud only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_type: Option<String>,
+    /// Data Center only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Data Center only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    /// Display name with the matched query highlighted in <strong> tags
+    pub html: String,
+    pub display_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar_url: Option<String>,
+}
+
+/// Identifies the assignee by account_id on Cloud and name on Data Center
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PostAssignBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
 
-    impl From<User> for PostAssignBody {
-        fn from(value: User) -> Self {
-            PostAssignBody { name: value.name }
+impl From<User> for PostAssignBody {
+    fn from(value: User) -> Self {
+        PostAssignBody {
+            account_id: value.account_id,
+            name: value.name,
         }
     }
 }
 
-#[cfg(feature = "cloud")]
-mod versioned {
-    use super::*;
+/// Jira timestamp, e.g. 2024-01-01T10:00:00.000+0000
+#[cfg(not(feature = "chrono"))]
+pub type JiraTimestamp = String;
 
-    #[derive(Deserialize, Debug, Clone)]
-    #[serde(rename_all = "camelCase")]
-    pub struct GetFilterSearchResponseBody {
-        // https://developer.atlassian.com/cloud/jira/platform/rest/v2/api-group-filters/#api-rest-api-2-filter-search-get
-        pub max_results: u32,
-        pub start_at: u32,
-        pub total: u32,
-        pub is_last: bool,
-        #[serde(alias = "values")]
-        pub filters: Vec<Filter>,
-    }
+/// Jira timestamp, e.g. 2024-01-01T10:00:00.000+0000
+#[cfg(feature = "chrono")]
+pub type JiraTimestamp = chrono::DateTime<chrono::FixedOffset>;
 
-    #[derive(Deserialize, Serialize, Debug, Clone)]
-    #[serde(rename_all = "camelCase")]
-    pub struct User {
-        pub active: bool,
-        pub display_name: String,
-        pub account_id: String,
-        pub email_address: String,
+/// Jira rejects RFC 3339 offsets with a colon, so timestamps use this exact format
+#[cfg(feature = "chrono")]
+pub(crate) mod timestamp {
+    use super::JiraTimestamp;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub const FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.3f%z";
+
+    pub fn serialize<S: Serializer>(
+        value: &JiraTimestamp,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&value.format(FORMAT))
     }
 
-    #[derive(Serialize, Debug, Clone)]
-    #[serde(rename_all = "camelCase")]
-    pub struct PostAssignBody {
-        pub account_id: String,
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<JiraTimestamp, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        JiraTimestamp::parse_from_str(&value, FORMAT).map_err(serde::de::Error::custom)
     }
+}
 
-    impl From<User> for PostAssignBody {
-        fn from(value: User) -> Self {
-            PostAssignBody {
-                account_id: value.account_id,
-            }
-        }
+use crate::JiraClientError;
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetFilterSearchResponseBody {
+    // https://developer.atlassian.com/cloud/jira/platform/rest/v2/api-group-filters/#api-rest-api-2-filter-search-get
+    pub max_results: u32,
+    pub start_at: u32,
+    pub total: u32,
+    pub is_last: bool,
+    #[serde(rename = "values")]
+    pub filters: Vec<Filter>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetFailedWebhooksResponseBody {
+    // https://developer.atlassian.com/cloud/jira/platform/rest/v2/api-group-webhooks/#api-rest-api-2-webhook-failed-get
+    pub max_results: u32,
+    pub values: Vec<FailedWebhook>,
+    /// Url of the next page, None when there are no more failed webhooks
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next: Option<String>,
+}
+
+impl GetFailedWebhooksResponseBody {
+    /// The after cursor of the next page, taken from next
+    pub fn next_after(&self) -> Option<i64> {
+        let next = url::Url::parse(self.next.as_deref()?).ok()?;
+        next.query_pairs()
+            .find(|(key, _)| key == "after" || key == "failedAfter")
+            .and_then(|(_, after)| after.parse().ok())
     }
 }
 
-pub use versioned::*;
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FailedWebhook {
+    pub id: String,
+    /// None for webhooks registered with excludeBody
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    pub url: String,
+    /// Milliseconds since epoch
+    pub failure_time: i64,
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PutWebhookRefreshBody {
+    pub webhook_ids: Vec<i64>,
+}
 
-use crate::JiraClientError;
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PutWebhookRefreshResponseBody {
+    /// Milliseconds since epoch
+    pub expiration_date: i64,
+}
 
 /// Define query parameters
 #[derive(Debug, Clone)]
@@ -84,251 +180,1827 @@ pub struct GetAssignableUserParams {
     pub project: Option<String>,
     pub issue_key: Option<IssueKey>,
     pub max_results: Option<u32>,
+    pub start_at: Option<u32>,
 }
 
-/// Comment related types
-#[derive(Serialize, Debug, Clone)]
+/// Define query parameters
+#[derive(Debug, Clone)]
+pub struct GetViewableUserParams {
+    pub username: Option<String>,
+    pub project: Option<String>,
+    pub issue_key: Option<IssueKey>,
+    pub max_results: Option<u32>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct PostCommentBody {
-    pub body: String,
+pub struct GetUserPickerResponseBody {
+    pub users: Vec<UserPickerUser>,
+    pub total: u32,
+    /// e.g. "Showing 20 of 25 matching users"
+    pub header: String,
 }
 
-/// Worklog related types
+/// Issue creation related types
 #[derive(Serialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct PostWorklogBody {
-    pub comment: String,
-    pub started: String,
-    pub time_spent: Option<String>,
-    pub time_spent_seconds: Option<String>,
+pub struct PostIssueBody {
+    pub fields: PostIssueFields,
+}
+
+impl PostIssueBody {
+    pub fn new(project: ProjectRef, issuetype: IssueTypeRef, summary: impl Into<String>) -> Self {
+        PostIssueBody {
+            fields: PostIssueFields {
+                project,
+                issuetype,
+                summary: summary.into(),
+                description: None,
+                security: None,
+                priority: None,
+                customfields: BTreeMap::new(),
+            },
+        }
+    }
 }
 
 #[derive(Serialize, Debug, Clone)]
+pub struct PostIssueFields {
+    pub project: ProjectRef,
+    pub issuetype: IssueTypeRef,
+    pub summary: String,
+    /// ADF requires the Cloud v3 API
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<RichText>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub security: Option<SecurityLevelRef>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<PriorityRef>,
+    /// Any other field by id, e.g. customfield_10016 or labels
+    #[serde(flatten)]
+    pub customfields: BTreeMap<String, Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectRef {
+    Id(String),
+    Key(String),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum IssueTypeRef {
+    Id(String),
+    Name(String),
+}
+
+/// Also valid as an edit value, e.g. EditIssueBody::with_field("priority", to_value(priority_ref))
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PriorityRef {
+    Id(String),
+    Name(String),
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct CreatedIssueResponse {
+    pub id: String,
+    pub key: IssueKey,
+    #[serde(rename = "self")]
+    pub self_ref: String,
+}
+
+/// Create meta related types, shared by Cloud and Data Center
+#[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-/// If duration unit is unspecififed, defaults to minutes.
-pub struct WorklogDuration(String);
+pub struct CreateMetaPage<T> {
+    pub start_at: u32,
+    pub max_results: u32,
+    pub total: u32,
+    /// Data Center only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_last: Option<bool>,
+    /// values on Data Center, issueTypes or fields on Cloud
+    #[serde(alias = "issueTypes", alias = "fields")]
+    pub values: Vec<T>,
+}
 
-impl Display for WorklogDuration {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{}", self.0)
-    }
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateMetaIssueType {
+    #[serde(rename = "self")]
+    pub self_ref: String,
+    pub id: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_url: Option<String>,
+    #[serde(default)]
+    pub subtask: bool,
 }
 
-static WORKLOG_RE: OnceLock<Regex> = OnceLock::new();
+/// Field on the create or edit screen
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldMetadata {
+    /// Filled in from the field key by get_edit_meta
+    #[serde(default)]
+    pub field_id: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    pub required: bool,
+    pub schema: FieldSchema,
+    #[serde(default)]
+    pub operations: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_values: Option<Vec<TransitionFieldAllowedValue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_complete_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_default_value: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_value: Option<Value>,
+}
 
-impl TryFrom<String> for WorklogDuration {
-    type Error = JiraClientError;
-    fn try_from(value: String) -> Result<Self, JiraClientError> {
-        let worklog_re = WORKLOG_RE.get_or_init(|| {
-            Regex::new(r"([0-9]+(?:\.[0-9]+)?)[WwDdHhMm]?").expect("Unable to compile WORKLOG_RE")
-        });
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct GetEditMetaResponseBody {
+    pub fields: BTreeMap<String, FieldMetadata>,
+}
 
-        let mut worklog = match worklog_re.captures(&value) {
-            Some(c) => match c.get(0) {
-                Some(worklog_match) => Ok(worklog_match.as_str().to_lowercase()),
-                None => Err(JiraClientError::TryFromError(
-                    "First capture is none: WORKLOG_RE".to_string(),
-                )),
-            },
-            None => Err(JiraClientError::TryFromError(
-                "Malformed worklog duration".to_string(),
-            )),
-        }?;
+/// Issue edit related types, fields and update can be combined but not for the same field
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct EditIssueBody {
+    /// Replace field values by field id
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub fields: BTreeMap<String, Value>,
+    /// Operations by field id, e.g. adding a label without replacing the others
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub update: BTreeMap<String, Vec<FieldOperation>>,
+}
 
-        let multiplier = match worklog.pop() {
-            Some('m') => 60,
-            Some('h') => 3600,
-            Some('d') => 3600 * 8,     // 8 Hours is default for cloud.
-            Some('w') => 3600 * 8 * 5, // 5 days of work in a week.
-            Some(maybe_digit) if maybe_digit.is_ascii_digit() => {
-                worklog.push(maybe_digit); // Unit was omitted
-                60
-            }
-            _ => 60, // Should never reach this due to the Regex Match, but try parsing input anyways.
-        };
+impl EditIssueBody {
+    pub fn with_field(mut self, id: impl Into<String>, value: Value) -> Self {
+        self.fields.insert(id.into(), value);
+        self
+    }
 
-        let seconds = worklog.parse::<f64>().map_err(|_| {
-            JiraClientError::TryFromError("Unexpected worklog duration input".to_string())
-        })? * f64::from(multiplier);
+    pub fn with_operation(mut self, id: impl Into<String>, operation: FieldOperation) -> Self {
+        self.update.entry(id.into()).or_default().push(operation);
+        self
+    }
 
-        Ok(WorklogDuration(format!("{:.0}", seconds)))
+    /// Add labels, keeping existing labels
+    pub fn add_labels<S: AsRef<str>>(self, labels: impl IntoIterator<Item = S>) -> Self {
+        labels.into_iter().fold(self, |body, label| {
+            body.with_operation("labels", FieldOperation::Add(label.as_ref().into()))
+        })
+    }
+
+    pub fn remove_labels<S: AsRef<str>>(self, labels: impl IntoIterator<Item = S>) -> Self {
+        labels.into_iter().fold(self, |body, label| {
+            body.with_operation("labels", FieldOperation::Remove(label.as_ref().into()))
+        })
     }
 }
 
-/// Issue related types
-#[derive(Serialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum FieldOperation {
+    Add(Value),
+    Set(Value),
+    Remove(Value),
+    Edit(Value),
+}
+
+/// Assignee for assign_issue
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AssigneeRef {
+    /// Cloud
+    AccountId(String),
+    /// Data Center
+    Username(String),
+    Unassigned,
+    /// Project default assignee
+    Default,
+}
+
+impl AssigneeRef {
+    /// Unassigned is null and Default is -1, keyed by accountId on cloud and name on Data Center
+    pub(crate) fn to_body(&self, is_cloud: bool) -> Value {
+        let key = if is_cloud { "accountId" } else { "name" };
+        match self {
+            AssigneeRef::AccountId(id) => serde_json::json!({ "accountId": id }),
+            AssigneeRef::Username(name) => serde_json::json!({ "name": name }),
+            AssigneeRef::Unassigned => serde_json::json!({ key: null }),
+            AssigneeRef::Default => serde_json::json!({ key: "-1" }),
+        }
+    }
+}
+
+/// Dashboard related types
+#[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct PostIssueQueryBody {
-    pub fields: Option<Vec<String>>,
-    pub jql: String,
-    pub max_results: u32,
-    pub start_at: u32,
-    /// Expects camelCase and the API is case-sensitive
-    pub expand: Option<Vec<String>>,
+pub struct Dashboard {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "self")]
+    pub self_ref: String,
+    /// Url of the dashboard in the browser
+    pub view: String,
+    /// Cloud only
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_favourite: Option<bool>,
+    /// Cloud only
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct PostIssueQueryResponseBody {
-    /// https://docs.atlassian.com/software/jira/docs/api/REST/7.6.1/#api/2/search
-    pub expand: String,
-    pub issues: Vec<Issue>,
-    pub max_results: u32,
+pub struct DashboardPage {
     pub start_at: u32,
+    pub max_results: u32,
     pub total: u32,
-    /// Some when expanding names on query_issues
-    pub names: Option<HashMap<String, String>>,
+    pub dashboards: Vec<Dashboard>,
 }
 
+/// Cloud only
 #[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct Issue {
-    pub expand: Option<String>,
-    pub fields: IssueFields,
-    pub id: String,
-    pub key: IssueKey,
-    #[serde(alias = "self")]
-    pub self_ref: String,
-    /// Some when expanding names on query_issue
-    pub names: Option<HashMap<String, String>>,
+pub struct DashboardGadget {
+    pub id: u64,
+    pub title: String,
+    /// e.g. blue
+    pub color: String,
+    pub position: GadgetPosition,
+    /// Set for gadgets from apps
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub module_key: Option<String>,
+    /// Set for gadgets from a gadget spec url
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct GadgetPosition {
+    pub row: u32,
+    pub column: u32,
 }
 
-/// All fields are optional as it's possible to define what fields you want in the request
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct GetDashboardGadgetsResponseBody {
+    pub gadgets: Vec<DashboardGadget>,
+}
+
+/// Server info related types
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerInfo {
+    pub base_url: String,
+    pub version: String,
+    pub version_numbers: Vec<u32>,
+    /// Cloud or Server, Data Center reports Server and older servers omit it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deployment_type: Option<String>,
+    pub build_number: u64,
+    pub build_date: String,
+    /// Omitted for anonymous requests on Cloud
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server_time: Option<String>,
+    pub scm_info: String,
+    pub server_title: String,
+}
+
+/// Error body returned by most endpoints on 4xx responses
 #[derive(Deserialize, Serialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
-pub struct IssueFields {
-    pub assignee: Option<User>,
-    pub components: Option<Vec<Component>>,
-    pub created: Option<String>,
-    pub creator: Option<User>,
-    pub description: Option<String>,
-    pub duedate: Option<String>,
-    pub labels: Option<Vec<String>>,
-    pub last_viewed: Option<String>,
-    pub reporter: Option<User>,
-    pub resolutiondate: Option<String>,
-    pub summary: Option<String>,
-    pub timeestimate: Option<u32>,
-    pub timeoriginalestimate: Option<u32>,
-    pub timespent: Option<u32>,
-    pub updated: Option<String>,
-    pub workratio: Option<i32>,
+pub struct ErrorResponseBody {
+    #[serde(default)]
+    pub error_messages: Vec<String>,
+    /// Field id -> error
+    #[serde(default)]
+    pub errors: BTreeMap<String, String>,
+}
 
-    // pub project: Project,            //TODO
-    // pub issuetype: IssueType,        //TODO
-    // pub status: Status,              //TODO
-    // pub comment: CommentContainer,   //TODO
-    // pub resolution: Resolution,      //TODO
-    // pub priority: Priority,          //TODO
-    // pub progress: Progress,          //TODO
-    // pub subtasks: Vec<Value>,        //TODO
-    // pub issue_links: Vec<Value>,     //TODO
-    // pub votes: Votes,                //TODO
-    // pub worklog: Worklog,            //TODO
-    // pub timetracking: TimeTracking,  //TODO
-    // pub watches: Watches,            //TODO
-    // pub fix_versions: Vec<Version>,  //TODO
-    // pub versions: Vec<Version>,      //TODO
-    // pub attachment: Vec<Attachment>, //TODO
-    #[serde(flatten)]
-    pub customfields: BTreeMap<String, Value>,
+/// JQL validation related types, Cloud only
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ParseJqlResponseBody {
+    pub queries: Vec<ParsedJqlQuery>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ParsedJqlQuery {
+    pub query: String,
+    #[serde(default)]
+    pub errors: Vec<String>,
+    #[serde(default)]
+    pub warnings: Vec<String>,
+}
+
+/// Label related types
+/// Cloud only
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LabelPage {
+    pub start_at: u32,
+    pub max_results: u32,
+    pub total: u32,
+    pub is_last: bool,
+    pub values: Vec<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct LabelSuggestions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    pub suggestions: Vec<LabelSuggestion>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct LabelSuggestion {
+    pub label: String,
+    /// Label with the matched query highlighted in <b> tags
+    pub html: String,
+}
+
+/// Group related types
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupPickerGroup {
+    pub name: String,
+    /// Name with the matched query highlighted in <b> tags
+    pub html: String,
+    /// Cloud only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_id: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct GetGroupsResponseBody {
+    /// e.g. "Showing 20 of 25 matching groups"
+    pub header: String,
+    pub total: u32,
+    pub groups: Vec<GroupPickerGroup>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupMemberPage {
+    pub start_at: u32,
+    pub max_results: u32,
+    pub total: u32,
+    pub is_last: bool,
+    pub values: Vec<User>,
+}
+
+/// Entity property related types, shared by issues and users
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct EntityPropertyKey {
+    #[serde(rename = "self")]
+    pub self_ref: String,
+    pub key: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct GetPropertyKeysResponseBody {
+    pub keys: Vec<EntityPropertyKey>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct EntityProperty {
+    pub key: String,
+    pub value: Value,
+}
+
+/// Comment related types
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PostCommentBody {
+    /// ADF requires the Cloud v3 API
+    pub body: RichText,
+    /// Restrict who can see the comment, visible to all users with access to the issue if None
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<Visibility>,
+}
+
+impl PostCommentBody {
+    pub fn new(body: impl Into<RichText>) -> Self {
+        PostCommentBody {
+            body: body.into(),
+            visibility: None,
+        }
+    }
+
+    pub fn with_visibility(mut self, visibility: Visibility) -> Self {
+        self.visibility = Some(visibility);
+        self
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Comment {
+    #[serde(rename = "self")]
+    pub self_ref: String,
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<User>,
+    pub body: RichText,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub update_author: Option<User>,
+    pub created: String,
+    pub updated: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<Visibility>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentPage {
+    pub start_at: u32,
+    pub max_results: u32,
+    pub total: u32,
+    pub comments: Vec<Comment>,
+}
+
+/// Worklog related types
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PostWorklogBody {
+    pub comment: String,
+    #[cfg_attr(feature = "chrono", serde(with = "timestamp"))]
+    pub started: JiraTimestamp,
+    pub time_spent: Option<String>,
+    pub time_spent_seconds: Option<String>,
+    /// Restrict who can see the worklog, visible to all users with access to the issue if None
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<Visibility>,
+}
+
+impl PostWorklogBody {
+    /// ```rust
+    /// use jira_issue_api::models::{PostWorklogBody, WorklogDuration};
+    ///
+    /// let body = PostWorklogBody::builder()
+    ///     .time_spent(WorklogDuration::try_from("1h".to_string()).unwrap())
+    ///     .comment("Review")
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(Some("3600".to_string()), body.time_spent_seconds);
+    /// ```
+    pub fn builder() -> PostWorklogBodyBuilder {
+        PostWorklogBodyBuilder::default()
+    }
+}
+
+/// Builds a PostWorklogBody with exactly one of time_spent and time_spent_seconds
+#[derive(Debug, Clone, Default)]
+pub struct PostWorklogBodyBuilder {
+    comment: String,
+    started: Option<JiraTimestamp>,
+    time_spent: Option<WorklogDuration>,
+    visibility: Option<Visibility>,
+}
+
+impl PostWorklogBodyBuilder {
+    pub fn time_spent(mut self, duration: WorklogDuration) -> Self {
+        self.time_spent = Some(duration);
+        self
+    }
+
+    /// Defaults to now
+    pub fn started(mut self, started: JiraTimestamp) -> Self {
+        self.started = Some(started);
+        self
+    }
+
+    pub fn comment(mut self, comment: impl Into<String>) -> Self {
+        self.comment = comment.into();
+        self
+    }
+
+    pub fn visibility(mut self, visibility: Visibility) -> Self {
+        self.visibility = Some(visibility);
+        self
+    }
+
+    pub fn build(self) -> Result<PostWorklogBody, JiraClientError> {
+        let time_spent = self.time_spent.ok_or_else(|| {
+            JiraClientError::JiraRequestBodyError("time_spent is required".to_string())
+        })?;
+
+        Ok(PostWorklogBody {
+            comment: self.comment,
+            started: self.started.unwrap_or_else(now),
+            time_spent: None,
+            time_spent_seconds: Some(time_spent.to_string()),
+            visibility: self.visibility,
+        })
+    }
+}
+
+#[cfg(feature = "chrono")]
+fn now() -> JiraTimestamp {
+    chrono::DateTime::<chrono::Utc>::from(crate::client::now()).fixed_offset()
+}
+
+/// UTC in the Jira timestamp format
+#[cfg(not(feature = "chrono"))]
+fn now() -> JiraTimestamp {
+    let since_epoch = crate::client::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default();
+    let seconds = since_epoch.as_secs();
+    let (days, time) = (seconds / 86400, seconds % 86400);
+
+    // Civil date from days since epoch, http://howardhinnant.github.io/date_algorithms.html
+    let z = days as i64 + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}+0000",
+        year,
+        month,
+        day,
+        time / 3600,
+        time % 3600 / 60,
+        time % 60,
+        since_epoch.subsec_millis()
+    )
+}
+
+/// Restricts a worklog or comment to a group or project role
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct Visibility {
+    #[serde(rename = "type")]
+    pub visibility_type: VisibilityType,
+    /// Group or role name
+    pub value: String,
+    /// Cloud group id, preferred over the group name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identifier: Option<String>,
+}
+
+impl Visibility {
+    pub fn group(name: impl Into<String>) -> Self {
+        Visibility {
+            visibility_type: VisibilityType::Group,
+            value: name.into(),
+            identifier: None,
+        }
+    }
+
+    pub fn role(name: impl Into<String>) -> Self {
+        Visibility {
+            visibility_type: VisibilityType::Role,
+            value: name.into(),
+            identifier: None,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum VisibilityType {
+    Group,
+    Role,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Worklog {
+    #[serde(rename = "self")]
+    pub self_ref: String,
+    pub id: String,
+    pub issue_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<User>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub update_author: Option<User>,
+    /// ADF document on the Cloud v3 API
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<RichText>,
+    #[cfg_attr(feature = "chrono", serde(with = "timestamp"))]
+    pub created: JiraTimestamp,
+    #[cfg_attr(feature = "chrono", serde(with = "timestamp"))]
+    pub updated: JiraTimestamp,
+    #[cfg_attr(feature = "chrono", serde(with = "timestamp"))]
+    pub started: JiraTimestamp,
+    pub time_spent: String,
+    pub time_spent_seconds: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<Visibility>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WorklogPage {
+    pub start_at: u32,
+    pub max_results: u32,
+    pub total: u32,
+    pub worklogs: Vec<Worklog>,
+}
+
+/// Query parameters of the audit log, from and to are ISO 8601 timestamps, e.g. 2024-01-31T00:00:00.000Z
+#[derive(Debug, Clone, Default)]
+pub struct GetAuditRecordsParams {
+    /// Matched against the summary, category, author and items of the records
+    pub filter: Option<String>,
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub offset: u32,
+    pub limit: Option<u32>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditRecord {
+    pub id: u64,
+    pub summary: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remote_address: Option<String>,
+    /// Data Center
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_key: Option<String>,
+    /// Cloud
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_account_id: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(with = "timestamp"))]
+    pub created: JiraTimestamp,
+    pub category: String,
+    pub event_source: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub object_item: Option<Value>,
+    #[serde(default)]
+    pub changed_values: Vec<Value>,
+    #[serde(default)]
+    pub associated_items: Vec<Value>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditRecordPage {
+    pub offset: u32,
+    pub limit: u32,
+    pub total: u32,
+    pub records: Vec<AuditRecord>,
+}
+
+/// Worklog updated or deleted since a timestamp
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WorklogChange {
+    pub worklog_id: u64,
+    /// Milliseconds since epoch
+    pub updated_time: i64,
+    #[serde(default)]
+    pub properties: Vec<Value>,
+}
+
+/// Continue from until while last_page is false
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WorklogChangePage {
+    pub values: Vec<WorklogChange>,
+    /// Milliseconds since epoch
+    pub since: i64,
+    /// Milliseconds since epoch
+    pub until: i64,
+    pub last_page: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page: Option<String>,
+    #[serde(rename = "self")]
+    pub self_ref: String,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct PostWorklogListBody {
+    pub ids: Vec<u64>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+/// If duration unit is unspecififed, defaults to minutes.
+pub struct WorklogDuration(String);
+
+impl Display for WorklogDuration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl WorklogDuration {
+    /// Jira duration in whole minutes, the smallest unit estimates accept, rounded up
+    fn to_minutes(&self) -> String {
+        let seconds = self.0.parse::<u64>().unwrap_or_default();
+        format!("{}m", seconds.div_ceil(60))
+    }
+}
+
+/// How the remaining estimate changes when logging, updating or deleting work
+#[derive(Debug, Clone, Default)]
+pub enum AdjustEstimate {
+    /// Reduce by the time spent, the Jira default
+    #[default]
+    Auto,
+    Leave,
+    /// Set the remaining estimate
+    New(WorklogDuration),
+    /// Reduce by the duration when logging work, increase when deleting it
+    Manual(WorklogDuration),
+}
+
+impl AdjustEstimate {
+    /// manual_key is reduceBy when logging work and increaseBy when deleting it
+    pub(crate) fn query(&self, manual_key: &str) -> String {
+        match self {
+            AdjustEstimate::Auto => "adjustEstimate=auto".to_string(),
+            AdjustEstimate::Leave => "adjustEstimate=leave".to_string(),
+            AdjustEstimate::New(duration) => {
+                format!("adjustEstimate=new&newEstimate={}", duration.to_minutes())
+            }
+            AdjustEstimate::Manual(duration) => {
+                format!(
+                    "adjustEstimate=manual&{}={}",
+                    manual_key,
+                    duration.to_minutes()
+                )
+            }
+        }
+    }
+}
+
+static WORKLOG_RE: OnceLock<Regex> = OnceLock::new();
+
+impl TryFrom<String> for WorklogDuration {
+    type Error = JiraClientError;
+    fn try_from(value: String) -> Result<Self, JiraClientError> {
+        let worklog_re = WORKLOG_RE.get_or_init(|| {
+            Regex::new(r"([0-9]+(?:\.[0-9]+)?)[WwDdHhMm]?").expect("Unable to compile WORKLOG_RE")
+        });
+
+        let mut worklog = match worklog_re.captures(&value) {
+            Some(c) => match c.get(0) {
+                Some(worklog_match) => Ok(worklog_match.as_str().to_lowercase()),
+                None => Err(JiraClientError::TryFromError(
+                    "First capture is none: WORKLOG_RE".to_string(),
+                )),
+            },
+            None => Err(JiraClientError::TryFromError(
+                "Malformed worklog duration".to_string(),
+            )),
+        }?;
+
+        let multiplier = match worklog.pop() {
+            Some('m') => 60,
+            Some('h') => 3600,
+            Some('d') => 3600 * 8,     // 8 Hours is default for cloud.
+            Some('w') => 3600 * 8 * 5, // 5 days of work in a week.
+            Some(maybe_digit) if maybe_digit.is_ascii_digit() => {
+                worklog.push(maybe_digit); // Unit was omitted
+                60
+            }
+            _ => 60, // Should never reach this due to the Regex Match, but try parsing input anyways.
+        };
+
+        let seconds = worklog.parse::<f64>().map_err(|_| {
+            JiraClientError::TryFromError("Unexpected worklog duration input".to_string())
+        })? * f64::from(multiplier);
+
+        Ok(WorklogDuration(format!("{:.0}", seconds)))
+    }
+}
+
+/// Issue related types
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PostIssueQueryBody {
+    pub fields: Option<Vec<String>>,
+    pub jql: String,
+    pub max_results: u32,
+    pub start_at: u32,
+    /// Expects camelCase and the API is case-sensitive
+    pub expand: Option<Vec<String>>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PostIssueQueryResponseBody {
+    /// https://docs.atlassian.com/software/jira/docs/api/REST/7.6.1/#api/2/search
+    pub expand: String,
+    pub issues: Vec<Issue>,
+    pub max_results: u32,
+    pub start_at: u32,
+    pub total: u32,
+    /// Some when expanding names on query_issues
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub names: Option<HashMap<String, String>>,
+}
+
+/// Cloud only, cursor based replacement of /search
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PostEnhancedSearchBody {
+    pub jql: String,
+    /// nextPageToken of the previous page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page_token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_results: Option<u32>,
+    /// Only id is returned if empty
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<String>,
+    /// Comma separated, e.g. names,changelog
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expand: Option<String>,
+    /// Include issues updated since the search index was last refreshed
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub reconcile_issues: Vec<u64>,
+}
+
+impl PostEnhancedSearchBody {
+    pub fn new(jql: impl Into<String>) -> Self {
+        PostEnhancedSearchBody {
+            jql: jql.into(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Cloud only, there is no total, continue with next_page_token until it is None
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EnhancedSearchResponseBody {
+    pub issues: Vec<Issue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page_token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_last: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub names: Option<HashMap<String, String>>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Issue {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expand: Option<String>,
+    pub fields: IssueFields,
+    pub id: String,
+    pub key: IssueKey,
+    #[serde(rename = "self")]
+    pub self_ref: String,
+    /// Some when expanding names on query_issue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub names: Option<HashMap<String, String>>,
+    /// Some when expanding changelog, at most the latest 100 histories
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changelog: Option<Changelog>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Changelog {
+    pub start_at: u32,
+    pub max_results: u32,
+    pub total: u32,
+    pub histories: Vec<ChangelogHistory>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangelogHistory {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<User>,
+    pub created: String,
+    pub items: Vec<ChangelogItem>,
+}
+
+/// A single field change, from/to are ids and from_string/to_string display values
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangelogItem {
+    pub field: String,
+    pub fieldtype: String,
+    /// Cloud only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field_id: Option<String>,
+    /// Jira sends null for unset from/to values, so they are kept
+    pub from: Option<String>,
+    pub from_string: Option<String>,
+    pub to: Option<String>,
+    pub to_string: Option<String>,
+}
+
+/// Cloud only
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetChangelogResponseBody {
+    pub start_at: u32,
+    pub max_results: u32,
+    pub total: u32,
+    pub is_last: bool,
+    pub values: Vec<ChangelogHistory>,
+}
+
+/// All fields are optional as it's possible to define what fields you want in the request.
+/// Jira sends null for empty requested fields, so None is serialized as null.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueFields {
+    pub assignee: Option<User>,
+    pub components: Option<Vec<Component>>,
+    pub created: Option<String>,
+    pub creator: Option<User>,
+    /// ADF document on the Cloud v3 API
+    pub description: Option<RichText>,
+    pub duedate: Option<String>,
+    pub labels: Option<Vec<String>>,
+    pub last_viewed: Option<String>,
+    pub reporter: Option<User>,
+    pub resolutiondate: Option<String>,
+    pub summary: Option<String>,
+    pub timeestimate: Option<u32>,
+    pub timeoriginalestimate: Option<u32>,
+    pub timespent: Option<u32>,
+    pub updated: Option<String>,
+    pub workratio: Option<i32>,
+    pub status: Option<Status>,
+    pub fix_versions: Option<Vec<Version>>,
+    pub versions: Option<Vec<Version>>,
+    pub security: Option<SecurityLevel>,
+    pub comment: Option<CommentPage>,
+    /// At most the first 20 worklogs, see get_all_worklogs
+    pub worklog: Option<WorklogPage>,
+    pub attachment: Option<Vec<Attachment>>,
+    pub issuelinks: Option<Vec<IssueLink>>,
+    pub watches: Option<Watches>,
+    pub votes: Option<Votes>,
+    pub issuetype: Option<IssueType>,
+    pub priority: Option<Priority>,
+    pub resolution: Option<Resolution>,
+
+    // pub project: Project,            //TODO
+    // pub progress: Progress,          //TODO
+    // pub subtasks: Vec<Value>,        //TODO
+    // pub timetracking: TimeTracking,  //TODO
+    #[serde(flatten)]
+    pub customfields: BTreeMap<String, Value>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Status {
+    #[serde(rename = "self")]
+    pub self_ref: String,
+    pub id: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_url: Option<String>,
+    pub status_category: StatusCategoryDetails,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Priority {
+    #[serde(rename = "self")]
+    pub self_ref: String,
+    pub id: String,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_url: Option<String>,
+    /// e.g. #d04437, not set on the issue field
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_color: Option<String>,
+}
+
+impl Display for Priority {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Resolution {
+    #[serde(rename = "self")]
+    pub self_ref: String,
+    pub id: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl Display for Resolution {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusCategoryDetails {
+    #[serde(rename = "self", skip_serializing_if = "Option::is_none")]
+    pub self_ref: Option<String>,
+    pub id: u32,
+    pub key: StatusCategory,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color_name: Option<String>,
+    pub name: String,
+}
+
+/// Matches statusCategory.key, names are localized and should not be relied on.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum StatusCategory {
+    /// "To Do"
+    New,
+    /// "In Progress"
+    Indeterminate,
+    Done,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl StatusCategory {
+    pub fn is_new(&self) -> bool {
+        *self == StatusCategory::New
+    }
+
+    pub fn is_in_progress(&self) -> bool {
+        *self == StatusCategory::Indeterminate
+    }
+
+    pub fn is_done(&self) -> bool {
+        *self == StatusCategory::Done
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Field {
+    pub id: String,
+    pub name: String,
+    pub custom: bool,
+    pub orderable: bool,
+    pub navigable: bool,
+    pub searchable: bool,
+    pub clause_names: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema: Option<FieldSchema>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldSchema {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom: Option<FieldSchemaType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_id: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<FieldSchemaType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system: Option<FieldSchemaType>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub field_type: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub enum FieldSchemaType {
+    Any,
+    Array,
+    Attachment,
+    CommentsPage,
+    Component,
+    Date,
+    Datetime,
+    Issuelinks,
+    Issuetype,
+    Number,
+    Option,
+    Priority,
+    Progress,
+    Project,
+    Resolution,
+    Securitylevel,
+    Status,
+    String,
+    Timetracking,
+    User,
+    Version,
+    Votes,
+    Watches,
+    Worklog,
+    #[serde(untagged)]
+    Custom(String),
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Filter {
+    #[serde(rename = "self")]
+    pub self_ref: String,
+    pub id: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub owner: User,
+    pub jql: String,
+    pub view_url: String,
+    pub search_url: String,
+    pub favourite: bool,
+    pub shared_users: FilterSharedUsers,
+    // pub subscriptions: FilterSubscriptions
+    #[serde(default)]
+    pub share_permissions: Vec<SharePermission>,
+}
+
+impl Display for Filter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(f, "{}: {}", self.name, self.jql)
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct FilterSharedUsers {
+    pub size: u32,
+    pub max_results: u32,
+    pub start_index: u32,
+    pub end_index: u32,
+    pub items: Vec<User>,
+}
+
+/// Who a filter is shared with, the field matching share_type is set
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct SharePermission {
+    pub id: u64,
+    /// e.g. global, loggedin, project, group or user
+    #[serde(rename = "type")]
+    pub share_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<Project>,
+    /// Set with project when shared with a project role
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<ProjectRole>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<SharePermissionGroup>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SharePermissionGroup {
+    pub name: String,
+    /// Cloud only
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group_id: Option<String>,
+}
+
+/// Body for add_filter_share_permission
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum PostSharePermissionBody {
+    Global,
+    /// Any logged in user
+    Authenticated,
+    #[serde(rename_all = "camelCase")]
+    Project {
+        project_id: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    ProjectRole {
+        project_id: String,
+        project_role_id: String,
+    },
+    Group {
+        groupname: String,
+    },
+}
+
+/// Column of a filter issue navigator view
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ColumnItem {
+    /// Field name, e.g. Summary
+    pub label: String,
+    /// Field id, e.g. summary
+    pub value: String,
+}
+
+/// Body for create_filter and update_filter
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PostFilterBody {
+    pub name: String,
+    pub jql: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub favourite: Option<bool>,
+}
+
+impl PostFilterBody {
+    pub fn new(name: impl Into<String>, jql: impl Into<String>) -> Self {
+        PostFilterBody {
+            name: name.into(),
+            jql: jql.into(),
+            description: None,
+            favourite: None,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Component {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "self")]
+    pub self_ref: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lead: Option<User>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee_type: Option<AssigneeType>,
+    /// Project key
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<u64>,
+}
+
+/// Default assignee of issues created with the component
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AssigneeType {
+    ProjectDefault,
+    ComponentLead,
+    ProjectLead,
+    Unassigned,
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PostComponentBody {
+    /// Project key
+    pub project: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Cloud only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lead_account_id: Option<String>,
+    /// Data Center only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lead_user_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee_type: Option<AssigneeType>,
+}
+
+impl PostComponentBody {
+    pub fn new(project: impl Into<String>, name: impl Into<String>) -> Self {
+        PostComponentBody {
+            project: project.into(),
+            name: name.into(),
+            description: None,
+            lead_account_id: None,
+            lead_user_name: None,
+            assignee_type: None,
+        }
+    }
+}
+
+/// Fields left as None are unchanged
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PutComponentBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Cloud only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lead_account_id: Option<String>,
+    /// Data Center only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lead_user_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee_type: Option<AssigneeType>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentIssueCount {
+    #[serde(rename = "self")]
+    pub self_ref: String,
+    pub issue_count: u64,
+}
+
+/// Project related types
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Project {
+    #[serde(rename = "self")]
+    pub self_ref: String,
+    pub id: String,
+    pub key: String,
+    pub name: String,
+    /// e.g. software, business or service_desk
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_type_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lead: Option<User>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_category: Option<ProjectCategory>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar_urls: Option<AvatarUrls>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub components: Option<Vec<Component>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub versions: Option<Vec<Version>>,
+    /// Role name -> role url
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub roles: Option<BTreeMap<String, String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_types: Option<Vec<IssueType>>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueType {
+    #[serde(rename = "self")]
+    pub self_ref: String,
+    pub id: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_url: Option<String>,
+    #[serde(default)]
+    pub subtask: bool,
+    /// Cloud only, e.g. 1 for epics, 0 for standard issue types and -1 for subtasks
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hierarchy_level: Option<i32>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ProjectCategory {
+    #[serde(rename = "self")]
+    pub self_ref: String,
+    pub id: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct AvatarUrls {
+    #[serde(rename = "16x16", skip_serializing_if = "Option::is_none")]
+    pub x16: Option<String>,
+    #[serde(rename = "24x24", skip_serializing_if = "Option::is_none")]
+    pub x24: Option<String>,
+    #[serde(rename = "32x32", skip_serializing_if = "Option::is_none")]
+    pub x32: Option<String>,
+    #[serde(rename = "48x48", skip_serializing_if = "Option::is_none")]
+    pub x48: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ProjectRole {
+    #[serde(rename = "self")]
+    pub self_ref: String,
+    pub id: u64,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub actors: Vec<RoleActor>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RoleActor {
+    pub id: u64,
+    pub display_name: String,
+    /// atlassian-user-role-actor or atlassian-group-role-actor
+    #[serde(rename = "type")]
+    pub actor_type: String,
+    /// Username or group name, not set for users on cloud
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Cloud only
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_user: Option<RoleActorUser>,
+    /// Cloud only
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_group: Option<RoleActorGroup>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RoleActorUser {
+    pub account_id: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RoleActorGroup {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_id: Option<String>,
+}
+
+/// user: accountIds on cloud, usernames on Data Center, group: group names
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct PostRoleActorsBody {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub user: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub group: Vec<String>,
+}
+
+/// A single role actor, user is an accountId on cloud, username on Data Center
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RoleActorRef {
+    User(String),
+    Group(String),
+}
+
+impl RoleActorRef {
+    pub(crate) fn query(&self) -> (&'static str, &str) {
+        match self {
+            RoleActorRef::User(user) => ("user", user),
+            RoleActorRef::Group(group) => ("group", group),
+        }
+    }
+}
+
+/// Statuses in the workflow of an issue type in a project
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ProjectIssueTypeStatuses {
+    #[serde(rename = "self")]
+    pub self_ref: String,
+    /// Issue type id
+    pub id: String,
+    /// Issue type name
+    pub name: String,
+    #[serde(default)]
+    pub subtask: bool,
+    pub statuses: Vec<Status>,
+}
+
+/// Cloud only
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectPage {
+    pub start_at: u32,
+    pub max_results: u32,
+    pub total: u32,
+    pub is_last: bool,
+    pub values: Vec<Project>,
+}
+
+/// Issue link related types
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct IssueLinkType {
+    #[serde(rename = "self")]
+    pub self_ref: String,
+    pub id: String,
+    /// e.g. Blocks
+    pub name: String,
+    /// e.g. is blocked by
+    pub inward: String,
+    /// e.g. blocks
+    pub outward: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetIssueLinkTypesResponseBody {
+    pub issue_link_types: Vec<IssueLinkType>,
+}
+
+/// Link as seen from the issue, only the other issue is set
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueLink {
+    #[serde(rename = "self")]
+    pub self_ref: String,
+    pub id: String,
+    #[serde(rename = "type")]
+    pub link_type: IssueLinkType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inward_issue: Option<LinkedIssue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outward_issue: Option<LinkedIssue>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct LinkedIssue {
+    #[serde(rename = "self")]
+    pub self_ref: String,
+    pub id: String,
+    pub key: IssueKey,
+    /// Summary, status, priority and issuetype
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Value>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct IssueKeyRef {
+    pub key: IssueKey,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct IssueLinkTypeRef {
+    pub name: String,
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PostIssueLinkBody {
+    #[serde(rename = "type")]
+    pub link_type: IssueLinkTypeRef,
+    pub inward_issue: IssueKeyRef,
+    pub outward_issue: IssueKeyRef,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<PostCommentBody>,
+}
+
+/// Watcher related types
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Watches {
+    #[serde(rename = "self")]
+    pub self_ref: String,
+    pub watch_count: u32,
+    pub is_watching: bool,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetWatchersResponseBody {
+    #[serde(rename = "self")]
+    pub self_ref: String,
+    pub watch_count: u32,
+    pub is_watching: bool,
+    pub watchers: Vec<User>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Votes {
+    #[serde(rename = "self")]
+    pub self_ref: String,
+    pub votes: u32,
+    pub has_voted: bool,
+    /// Only from get_votes, requires permission to view voters
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub voters: Vec<User>,
+}
+
+/// Remote (web) link related types
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteLink {
+    #[serde(rename = "self")]
+    pub self_ref: String,
+    pub id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub global_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application: Option<RemoteLinkApplication>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relationship: Option<String>,
+    pub object: RemoteLinkObject,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct RemoteLinkApplication {
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub application_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct RemoteLinkObject {
+    pub url: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<RemoteLinkIcon>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<RemoteLinkStatus>,
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct Field {
-    pub id: String,
-    pub name: String,
-    pub custom: bool,
-    pub orderable: bool,
-    pub navigable: bool,
-    pub searchable: bool,
-    pub clause_names: Vec<String>,
-    pub schema: Option<FieldSchema>,
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct RemoteLinkIcon {
+    #[serde(rename = "url16x16", skip_serializing_if = "Option::is_none")]
+    pub url_16x16: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link: Option<String>,
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+/// Resolved links are shown struck through
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct RemoteLinkStatus {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolved: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<RemoteLinkIcon>,
+}
+
+/// An existing link on the issue with the same global_id is updated instead of duplicated
+#[derive(Serialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct FieldSchema {
-    pub custom: Option<FieldSchemaType>,
-    pub custom_id: Option<u32>,
-    pub items: Option<FieldSchemaType>,
-    pub system: Option<FieldSchemaType>,
-    #[serde(alias = "type")]
-    pub field_type: Option<String>,
+pub struct PostRemoteLinkBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub global_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application: Option<RemoteLinkApplication>,
+    /// e.g. "causes" or "mentioned in"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relationship: Option<String>,
+    pub object: RemoteLinkObject,
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
-#[serde(rename_all = "kebab-case")]
-pub enum FieldSchemaType {
-    Any,
-    Array,
-    Attachment,
-    CommentsPage,
-    Component,
-    Date,
-    Datetime,
-    Issuelinks,
-    Issuetype,
-    Number,
-    Option,
-    Priority,
-    Progress,
-    Project,
-    Resolution,
-    Securitylevel,
-    Status,
-    String,
-    Timetracking,
-    User,
-    Version,
-    Votes,
-    Watches,
-    Worklog,
-    #[serde(untagged)]
-    Custom(String),
+pub struct PostRemoteLinkResponseBody {
+    #[serde(rename = "self")]
+    pub self_ref: String,
+    pub id: u64,
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
-pub struct Filter {
-    #[serde(alias = "self")]
+pub struct SecurityLevel {
+    #[serde(rename = "self")]
     pub self_ref: String,
     pub id: String,
     pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
-    pub owner: User,
-    pub jql: String,
-    pub view_url: String,
-    pub search_url: String,
-    pub favourite: bool,
-    pub shared_users: FilterSharedUsers,
-    // pub subscriptions: FilterSubscriptions
-    // pub share_permissions: FilterSharePermissions
 }
 
-impl Display for Filter {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        write!(f, "{}: {}", self.name, self.jql)
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct GetSecurityLevelsResponseBody {
+    pub levels: Vec<SecurityLevel>,
+}
+
+/// Issue security level by id, for the security field when creating or editing issues
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct SecurityLevelRef {
+    pub id: String,
+}
+
+impl From<&SecurityLevel> for SecurityLevelRef {
+    fn from(level: &SecurityLevel) -> Self {
+        SecurityLevelRef {
+            id: level.id.clone(),
+        }
     }
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
-#[serde(rename_all = "kebab-case")]
-pub struct FilterSharedUsers {
-    pub size: u32,
-    pub max_results: u32,
-    pub start_index: u32,
-    pub end_index: u32,
-    pub items: Vec<User>,
+#[serde(rename_all = "camelCase")]
+pub struct Attachment {
+    #[serde(rename = "self")]
+    pub self_ref: String,
+    pub id: String,
+    pub filename: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<User>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<String>,
+    pub size: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+    pub content: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<String>,
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
-pub struct Component {
+#[serde(rename_all = "camelCase")]
+pub struct Version {
     pub id: String,
     pub name: String,
-    #[serde(alias = "self")]
+    #[serde(rename = "self")]
     pub self_ref: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub archived: bool,
+    #[serde(default)]
+    pub released: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub release_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overdue: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<u64>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PostVersionBody {
+    pub project_id: u64,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub released: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+    /// e.g. 2024-01-31
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    /// e.g. 2024-01-31
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub release_date: Option<String>,
+}
+
+impl PostVersionBody {
+    pub fn new(project_id: u64, name: impl Into<String>) -> Self {
+        PostVersionBody {
+            project_id,
+            name: name.into(),
+            description: None,
+            released: None,
+            archived: None,
+            start_date: None,
+            release_date: None,
+        }
+    }
+}
+
+/// Fields left as None are unchanged
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PutVersionBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub released: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub release_date: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VersionPosition {
+    First,
+    Last,
+    Earlier,
+    Later,
+}
+
+/// Position within the project versions, either relative or after another version
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum PostVersionMoveBody {
+    Position(VersionPosition),
+    /// self url of the version to move after
+    After(String),
 }
 
 impl Display for Issue {
@@ -345,6 +2017,29 @@ impl Display for Issue {
     }
 }
 
+impl Issue {
+    /// None if status is missing from the query response
+    pub fn status_category(&self) -> Option<&StatusCategory> {
+        self.fields
+            .status
+            .as_ref()
+            .map(|status| &status.status_category.key)
+    }
+
+    pub fn is_new(&self) -> bool {
+        self.status_category().is_some_and(StatusCategory::is_new)
+    }
+
+    pub fn is_in_progress(&self) -> bool {
+        self.status_category()
+            .is_some_and(StatusCategory::is_in_progress)
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.status_category().is_some_and(StatusCategory::is_done)
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct IssueKey(String);
 
@@ -400,13 +2095,17 @@ pub struct Transition {
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct TransitionExpandedFields {
     pub required: bool,
     pub name: String,
     pub operations: Vec<String>,
     pub schema: TransitionExpandedFieldsSchema,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub allowed_values: Option<Vec<TransitionFieldAllowedValue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub has_default_value: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub default_value: Option<String>,
 }
 
@@ -414,23 +2113,39 @@ pub struct TransitionExpandedFields {
 #[serde(untagged)]
 pub enum TransitionFieldAllowedValue {
     Str(String),
+    /// Options have a value, other objects such as resolutions have a name
     Object {
-        #[serde(alias = "self")]
+        #[serde(rename = "self")]
         self_ref: String,
-        #[serde(alias = "name")]
-        value: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        value: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<String>,
         id: String,
     },
 }
 
+impl TransitionFieldAllowedValue {
+    /// value or name of the allowed value
+    pub fn value(&self) -> &str {
+        match self {
+            TransitionFieldAllowedValue::Str(value) => value,
+            TransitionFieldAllowedValue::Object { value, name, .. } => {
+                value.as_deref().or(name.as_deref()).unwrap_or_default()
+            }
+        }
+    }
+}
+
 #[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct TransitionExpandedFieldsSchema {
-    #[serde(alias = "type")]
+    #[serde(rename = "type")]
     pub schema_type: String,
     pub items: String,
     pub custom: String,
     pub custom_id: u32,
-    #[cfg(not(feature = "cloud"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub system: Option<String>,
 }
 
@@ -451,6 +2166,19 @@ pub struct PostTransitionBody {
     pub update: Option<PostTransitionUpdateField>,
 }
 
+impl PostTransitionBody {
+    /// Set the resolution, required by transitions with a resolution screen
+    pub fn with_resolution(mut self, resolution: &Resolution) -> Self {
+        self.fields.get_or_insert_with(HashMap::new).insert(
+            "resolution".to_string(),
+            PostTransitionFieldBody {
+                name: resolution.name.clone(),
+            },
+        );
+        self
+    }
+}
+
 /// Server
 #[derive(Serialize, Debug, Clone)]
 pub struct PostTransitionUpdateField {
@@ -528,10 +2256,709 @@ mod tests {
         assert!(issue.is_ok());
     }
 
+    #[test]
+    fn status_category_deserialize_known_and_unknown_keys() -> Result<(), serde_json::Error> {
+        let categories = vec![
+            (StatusCategory::New, "\"new\""),
+            (StatusCategory::Indeterminate, "\"indeterminate\""),
+            (StatusCategory::Done, "\"done\""),
+            (
+                StatusCategory::Unknown("undefined".to_string()),
+                "\"undefined\"",
+            ),
+        ];
+
+        for (expected, input) in categories {
+            assert_eq!(expected, serde_json::from_str::<StatusCategory>(input)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn issue_is_done_without_status_is_false() {
+        let issue = Issue {
+            expand: None,
+            fields: IssueFields::default(),
+            id: String::from("10000"),
+            key: IssueKey(String::from("JB-1")),
+            self_ref: String::new(),
+            names: None,
+            changelog: None,
+        };
+        assert!(!issue.is_done());
+    }
+
+    /// Jira sends explicit nulls that are skipped when serializing, so null and absent are equal.
+    fn assert_round_trip<T: Serialize + serde::de::DeserializeOwned>(
+        input: &str,
+    ) -> Result<(), serde_json::Error> {
+        let expected = serde_json::from_str::<Value>(input)?;
+        let model = serde_json::from_str::<T>(input)?;
+        assert_eq!(
+            without_nulls(expected),
+            without_nulls(serde_json::to_value(model)?)
+        );
+        Ok(())
+    }
+
+    fn without_nulls(value: Value) -> Value {
+        match value {
+            Value::Object(map) => Value::Object(
+                map.into_iter()
+                    .filter(|(_, v)| !v.is_null())
+                    .map(|(k, v)| (k, without_nulls(v)))
+                    .collect(),
+            ),
+            Value::Array(values) => Value::Array(values.into_iter().map(without_nulls).collect()),
+            value => value,
+        }
+    }
+
+    #[test]
+    fn failed_webhooks_next_after() -> Result<(), serde_json::Error> {
+        let page = serde_json::from_str::<GetFailedWebhooksResponseBody>(
+            r#"{
+                "maxResults": 100,
+                "values": [],
+                "next": "https://domain.atlassian.net/rest/api/2/webhook/failed?failedAfter=1573540473480&maxResults=100"
+            }"#,
+        )?;
+        assert_eq!(Some(1573540473480), page.next_after());
+
+        let last = serde_json::from_str::<GetFailedWebhooksResponseBody>(
+            r#"{"maxResults": 100, "values": []}"#,
+        )?;
+        assert_eq!(None, last.next_after());
+        Ok(())
+    }
+
+    #[test]
+    fn user_round_trip() -> Result<(), serde_json::Error> {
+        assert_round_trip::<User>(
+            r#"{
+                "active": true,
+                "displayName": "Jane Doe",
+                "accountId": "5b10ac8d82e05b22cc7d4ef5",
+                "emailAddress": "jane@example.com",
+                "timeZone": "Europe/Copenhagen",
+                "locale": "en_US"
+            }"#,
+        )?;
+        assert_round_trip::<User>(
+            r#"{
+                "active": true,
+                "displayName": "Jane Doe",
+                "name": "jdoe",
+                "deleted": false
+            }"#,
+        )
+    }
+
+    #[test]
+    fn post_assign_body_from_user() -> Result<(), serde_json::Error> {
+        let user = serde_json::from_str::<User>(
+            r#"{"active": true, "displayName": "Jane Doe", "name": "jdoe"}"#,
+        )?;
+        assert_eq!(Some("jdoe"), user.id());
+        assert_eq!(
+            serde_json::json!({"name": "jdoe"}),
+            serde_json::to_value(PostAssignBody::from(user))?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn server_info_round_trip() -> Result<(), serde_json::Error> {
+        assert_round_trip::<ServerInfo>(
+            r#"{
+                "baseUrl": "https://jira.example.com",
+                "version": "9.12.2",
+                "versionNumbers": [9, 12, 2],
+                "deploymentType": "Server",
+                "buildNumber": 9120002,
+                "buildDate": "2024-01-01T00:00:00.000+0000",
+                "serverTime": "2024-06-01T12:00:00.000+0000",
+                "scmInfo": "0123456789abcdef",
+                "serverTitle": "Jira"
+            }"#,
+        )
+    }
+
+    #[test]
+    fn issue_round_trip() -> Result<(), serde_json::Error> {
+        assert_round_trip::<Issue>(
+            r#"{
+                "expand": "names",
+                "id": "10000",
+                "key": "JB-1",
+                "self": "https://jira.example.com/rest/api/2/issue/10000",
+                "names": null,
+                "fields": {
+                    "assignee": null,
+                    "components": [{"id": "1", "name": "api", "self": "https://jira.example.com/rest/api/2/component/1"}],
+                    "created": "2024-01-01T10:00:00.000+0000",
+                    "creator": null,
+                    "description": null,
+                    "duedate": null,
+                    "labels": ["a"],
+                    "lastViewed": null,
+                    "reporter": null,
+                    "resolutiondate": null,
+                    "summary": "Summary",
+                    "timeestimate": null,
+                    "timeoriginalestimate": null,
+                    "timespent": 60,
+                    "updated": null,
+                    "workratio": -1,
+                    "status": {
+                        "self": "https://jira.example.com/rest/api/2/status/1",
+                        "id": "1",
+                        "name": "Open",
+                        "description": null,
+                        "iconUrl": null,
+                        "statusCategory": {
+                            "self": "https://jira.example.com/rest/api/2/statuscategory/2",
+                            "id": 2,
+                            "key": "new",
+                            "colorName": "blue-gray",
+                            "name": "To Do"
+                        }
+                    },
+                    "fixVersions": [{
+                        "self": "https://jira.example.com/rest/api/2/version/10001",
+                        "id": "10001",
+                        "name": "1.0",
+                        "description": null,
+                        "archived": false,
+                        "released": true,
+                        "startDate": null,
+                        "releaseDate": "2024-02-01",
+                        "overdue": null,
+                        "projectId": 10000
+                    }],
+                    "versions": null,
+                    "comment": {
+                        "startAt": 0,
+                        "maxResults": 1,
+                        "total": 1,
+                        "comments": [{
+                            "self": "https://jira.example.com/rest/api/2/issue/10000/comment/10200",
+                            "id": "10200",
+                            "author": null,
+                            "body": "Comment",
+                            "updateAuthor": null,
+                            "created": "2024-01-01T10:00:00.000+0000",
+                            "updated": "2024-01-01T10:00:00.000+0000",
+                            "visibility": {"type": "role", "value": "Developers"}
+                        }]
+                    },
+                    "worklog": {
+                        "startAt": 0,
+                        "maxResults": 20,
+                        "total": 1,
+                        "worklogs": [{
+                            "self": "https://jira.example.com/rest/api/2/issue/10000/worklog/10300",
+                            "id": "10300",
+                            "issueId": "10000",
+                            "author": null,
+                            "updateAuthor": null,
+                            "comment": "Review",
+                            "created": "2024-01-01T10:00:00.000+0000",
+                            "updated": "2024-01-01T10:00:00.000+0000",
+                            "started": "2024-01-01T09:00:00.000+0000",
+                            "timeSpent": "1h",
+                            "timeSpentSeconds": 3600
+                        }]
+                    },
+                    "attachment": [{
+                        "self": "https://jira.example.com/rest/api/2/attachment/10400",
+                        "id": "10400",
+                        "filename": "build.log",
+                        "author": null,
+                        "created": "2024-01-01T10:00:00.000+0000",
+                        "size": 1024,
+                        "mimeType": "text/plain",
+                        "content": "https://jira.example.com/secure/attachment/10400/build.log",
+                        "thumbnail": null
+                    }],
+                    "issuelinks": null,
+                    "watches": {
+                        "self": "https://jira.example.com/rest/api/2/issue/JB-1/watchers",
+                        "watchCount": 1,
+                        "isWatching": false
+                    },
+                    "votes": {
+                        "self": "https://jira.example.com/rest/api/2/issue/JB-1/votes",
+                        "votes": 2,
+                        "hasVoted": true
+                    },
+                    "security": {
+                        "self": "https://jira.example.com/rest/api/2/securitylevel/10000",
+                        "id": "10000",
+                        "name": "Internal",
+                        "description": "Staff only"
+                    },
+                    "issuetype": {
+                        "self": "https://jira.example.com/rest/api/2/issuetype/10002",
+                        "id": "10002",
+                        "name": "Epic",
+                        "description": "A big user story",
+                        "iconUrl": "https://jira.example.com/images/icons/issuetypes/epic.svg",
+                        "subtask": false,
+                        "hierarchyLevel": 1
+                    },
+                    "resolution": {
+                        "self": "https://jira.example.com/rest/api/2/resolution/10000",
+                        "id": "10000",
+                        "name": "Done",
+                        "description": "Work has been completed on this issue."
+                    },
+                    "priority": {
+                        "self": "https://jira.example.com/rest/api/2/priority/2",
+                        "id": "2",
+                        "name": "High",
+                        "iconUrl": "https://jira.example.com/images/icons/priorities/high.svg"
+                    },
+                    "customfield_10000": {"value": "custom"}
+                }
+            }"#,
+        )
+    }
+
+    #[test]
+    fn visibility_round_trip() -> Result<(), serde_json::Error> {
+        assert_round_trip::<Visibility>(r#"{"type": "role", "value": "Developers"}"#)?;
+        assert_round_trip::<Visibility>(
+            r#"{"type": "group", "value": "jira-staff", "identifier": "276f955c-63d7-42c8-9520-92d01dca0625"}"#,
+        )
+    }
+
+    #[test]
+    fn post_issue_body() -> Result<(), serde_json::Error> {
+        let mut body = PostIssueBody::new(
+            ProjectRef::Key("JB".to_string()),
+            IssueTypeRef::Name("Bug".to_string()),
+            "Summary",
+        );
+        body.fields.priority = Some(PriorityRef::Name("High".to_string()));
+        body.fields
+            .customfields
+            .insert("customfield_10016".to_string(), serde_json::json!(3));
+
+        assert_eq!(
+            serde_json::json!({"fields": {
+                "project": {"key": "JB"},
+                "issuetype": {"name": "Bug"},
+                "summary": "Summary",
+                "priority": {"name": "High"},
+                "customfield_10016": 3,
+            }}),
+            serde_json::to_value(&body)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn post_transition_body_with_resolution() -> Result<(), serde_json::Error> {
+        let resolution = serde_json::from_str::<Resolution>(
+            r#"{"self": "https://jira.example.com/rest/api/2/resolution/10000", "id": "10000", "name": "Done", "description": null}"#,
+        )?;
+        let body = PostTransitionBody {
+            transition: PostTransitionIdBody {
+                id: "31".to_string(),
+            },
+            fields: None,
+            update: None,
+        }
+        .with_resolution(&resolution);
+
+        assert_eq!(
+            serde_json::json!({
+                "transition": {"id": "31"},
+                "fields": {"resolution": {"name": "Done"}},
+                "update": null,
+            }),
+            serde_json::to_value(&body)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn post_enhanced_search_body() -> Result<(), serde_json::Error> {
+        let body = PostEnhancedSearchBody {
+            next_page_token: Some("token".to_string()),
+            fields: vec!["summary".to_string()],
+            ..PostEnhancedSearchBody::new("project = JB")
+        };
+        assert_eq!(
+            serde_json::json!({"jql": "project = JB", "nextPageToken": "token", "fields": ["summary"]}),
+            serde_json::to_value(&body)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn post_share_permission_body() -> Result<(), serde_json::Error> {
+        assert_eq!(
+            serde_json::json!({"type": "global"}),
+            serde_json::to_value(PostSharePermissionBody::Global)?
+        );
+        assert_eq!(
+            serde_json::json!({"type": "projectRole", "projectId": "10000", "projectRoleId": "10001"}),
+            serde_json::to_value(PostSharePermissionBody::ProjectRole {
+                project_id: "10000".to_string(),
+                project_role_id: "10001".to_string(),
+            })?
+        );
+        assert_eq!(
+            serde_json::json!({"type": "group", "groupname": "jira-developers"}),
+            serde_json::to_value(PostSharePermissionBody::Group {
+                groupname: "jira-developers".to_string(),
+            })?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn edit_issue_body() -> Result<(), serde_json::Error> {
+        let body = EditIssueBody::default()
+            .with_field("summary", serde_json::json!("Summary"))
+            .with_operation("labels", FieldOperation::Add(serde_json::json!("a")))
+            .with_operation("labels", FieldOperation::Remove(serde_json::json!("b")));
+
+        assert_eq!(
+            serde_json::json!({
+                "fields": {"summary": "Summary"},
+                "update": {"labels": [{"add": "a"}, {"remove": "b"}]},
+            }),
+            serde_json::to_value(&body)?
+        );
+        assert_eq!(
+            serde_json::json!({}),
+            serde_json::to_value(EditIssueBody::default())?
+        );
+        assert_eq!(
+            serde_json::json!({"update": {"labels": [{"add": "a"}, {"add": "b"}, {"remove": "c"}]}}),
+            serde_json::to_value(
+                EditIssueBody::default()
+                    .add_labels(["a", "b"])
+                    .remove_labels(["c"])
+            )?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn create_meta_page_accepts_cloud_and_data_center() -> Result<(), serde_json::Error> {
+        let field = r#"{
+            "fieldId": "priority",
+            "name": "Priority",
+            "required": true,
+            "schema": {"type": "priority", "system": "priority"},
+            "operations": ["set"],
+            "allowedValues": [{"self": "https://jira.example.com/rest/api/2/priority/1", "name": "High", "id": "1"}]
+        }"#;
+        let data_center = serde_json::from_str::<CreateMetaPage<FieldMetadata>>(&format!(
+            r#"{{"startAt": 0, "maxResults": 50, "total": 1, "isLast": true, "values": [{}]}}"#,
+            field
+        ))?;
+        let cloud = serde_json::from_str::<CreateMetaPage<FieldMetadata>>(&format!(
+            r#"{{"startAt": 0, "maxResults": 50, "total": 1, "fields": [{}]}}"#,
+            field
+        ))?;
+
+        for page in [data_center, cloud] {
+            let field = &page.values[0];
+            assert!(field.required);
+            assert_eq!(
+                Some("High"),
+                field.allowed_values.as_ref().map(|v| v[0].value())
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn changelog_history_round_trip() -> Result<(), serde_json::Error> {
+        assert_round_trip::<ChangelogHistory>(
+            r#"{
+                "id": "10100",
+                "author": null,
+                "created": "2024-01-01T10:00:00.000+0000",
+                "items": [{
+                    "field": "status",
+                    "fieldtype": "jira",
+                    "from": "1",
+                    "fromString": "Open",
+                    "to": "3",
+                    "toString": "In Progress"
+                }]
+            }"#,
+        )
+    }
+
+    #[test]
+    fn post_comment_body_visibility() -> Result<(), serde_json::Error> {
+        assert_eq!(
+            serde_json::json!({"body": "Public"}),
+            serde_json::to_value(PostCommentBody::new("Public"))?
+        );
+        assert_eq!(
+            serde_json::json!({"body": "Internal", "visibility": {"type": "role", "value": "Developers"}}),
+            serde_json::to_value(
+                PostCommentBody::new("Internal").with_visibility(Visibility::role("Developers"))
+            )?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn adjust_estimate_query() -> Result<(), JiraClientError> {
+        assert_eq!(
+            "adjustEstimate=leave",
+            AdjustEstimate::Leave.query("reduceBy")
+        );
+        assert_eq!(
+            "adjustEstimate=new&newEstimate=120m",
+            AdjustEstimate::New(WorklogDuration::try_from("2h".to_string())?).query("reduceBy")
+        );
+        assert_eq!(
+            "adjustEstimate=manual&increaseBy=30m",
+            AdjustEstimate::Manual(WorklogDuration::try_from("30".to_string())?)
+                .query("increaseBy")
+        );
+        assert_eq!(
+            "adjustEstimate=manual&reduceBy=1m",
+            AdjustEstimate::Manual(WorklogDuration::try_from("0.5m".to_string())?)
+                .query("reduceBy")
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn jira_timestamp_format() -> Result<(), serde_json::Error> {
+        #[derive(Serialize, Deserialize)]
+        struct Started(#[serde(with = "timestamp")] JiraTimestamp);
+
+        let started = serde_json::from_str::<Started>(r#""2024-01-01T10:00:00.000+0100""#)?;
+        assert_eq!(3600, started.0.offset().local_minus_utc());
+        assert_eq!(
+            r#""2024-01-01T10:00:00.000+0100""#,
+            serde_json::to_string(&started)?
+        );
+        assert!(serde_json::from_str::<Started>(r#""2024-01-01""#).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn post_worklog_body_builder() -> Result<(), JiraClientError> {
+        assert!(matches!(
+            PostWorklogBody::builder().comment("No time").build(),
+            Err(JiraClientError::JiraRequestBodyError(_))
+        ));
+
+        let body = PostWorklogBody::builder()
+            .time_spent(WorklogDuration::try_from("1h".to_string())?)
+            .build()?;
+        assert_eq!(None, body.time_spent);
+        assert_eq!(Some("3600".to_string()), body.time_spent_seconds);
+
+        let started = serde_json::to_value(&body).unwrap()["started"].clone();
+        let started_re = Regex::new(
+            r"^[0-9]{4}-[0-9]{2}-[0-9]{2}T[0-9]{2}:[0-9]{2}:[0-9]{2}\.[0-9]{3}[+-][0-9]{4}$",
+        )
+        .unwrap();
+        assert!(started_re.is_match(started.as_str().unwrap()));
+        Ok(())
+    }
+
+    #[test]
+    fn remote_link_round_trip() -> Result<(), serde_json::Error> {
+        assert_round_trip::<RemoteLink>(
+            r#"{
+                "self": "https://jira.example.com/rest/api/2/issue/JB-1/remotelink/10000",
+                "id": 10000,
+                "globalId": "system=https://ci.example.com&id=1",
+                "application": {"type": "com.example.ci", "name": "CI"},
+                "relationship": "built by",
+                "object": {
+                    "url": "https://ci.example.com/1",
+                    "title": "Build 1",
+                    "icon": {"url16x16": "https://ci.example.com/favicon.png", "title": "CI"},
+                    "status": {"resolved": true}
+                }
+            }"#,
+        )
+    }
+
+    #[test]
+    fn project_round_trip() -> Result<(), serde_json::Error> {
+        assert_round_trip::<Project>(
+            r#"{
+                "self": "https://jira.example.com/rest/api/2/project/10000",
+                "id": "10000",
+                "key": "JB",
+                "name": "Jira Bugs",
+                "projectTypeKey": "software",
+                "lead": null,
+                "description": "Bugs in Jira",
+                "roles": {"Developers": "https://jira.example.com/rest/api/2/project/10000/role/10001"},
+                "issueTypes": [{
+                    "self": "https://jira.example.com/rest/api/2/issuetype/10001",
+                    "id": "10001",
+                    "name": "Bug",
+                    "description": "A problem",
+                    "iconUrl": null,
+                    "subtask": false
+                }],
+                "projectCategory": {
+                    "self": "https://jira.example.com/rest/api/2/projectCategory/10000",
+                    "id": "10000",
+                    "name": "Internal",
+                    "description": null
+                },
+                "avatarUrls": {
+                    "16x16": "https://jira.example.com/secure/projectavatar?size=xsmall&pid=10000",
+                    "24x24": "https://jira.example.com/secure/projectavatar?size=small&pid=10000",
+                    "32x32": "https://jira.example.com/secure/projectavatar?size=medium&pid=10000",
+                    "48x48": "https://jira.example.com/secure/projectavatar?pid=10000"
+                }
+            }"#,
+        )
+    }
+
+    #[test]
+    fn role_actors_round_trip() -> Result<(), serde_json::Error> {
+        assert_round_trip::<ProjectRole>(
+            r#"{
+                "self": "https://jira.example.com/rest/api/2/project/JB/role/10001",
+                "id": 10001,
+                "name": "Developers",
+                "description": null,
+                "actors": [
+                    {
+                        "id": 10100,
+                        "displayName": "Jane Doe",
+                        "type": "atlassian-user-role-actor",
+                        "name": null,
+                        "actorUser": {"accountId": "5b10ac8d82e05b22cc7d4ef5"}
+                    },
+                    {
+                        "id": 10101,
+                        "displayName": "jira-developers",
+                        "type": "atlassian-group-role-actor",
+                        "name": "jira-developers"
+                    }
+                ]
+            }"#,
+        )
+    }
+
+    #[test]
+    fn assignee_ref_body() {
+        assert_eq!(
+            serde_json::json!({"accountId": "5b10ac8d82e05b22cc7d4ef5"}),
+            AssigneeRef::AccountId("5b10ac8d82e05b22cc7d4ef5".to_string()).to_body(true)
+        );
+        assert_eq!(
+            serde_json::json!({"name": "jdoe"}),
+            AssigneeRef::Username("jdoe".to_string()).to_body(false)
+        );
+        assert_eq!(
+            serde_json::json!({"accountId": null}),
+            AssigneeRef::Unassigned.to_body(true)
+        );
+        assert_eq!(
+            serde_json::json!({"name": "-1"}),
+            AssigneeRef::Default.to_body(false)
+        );
+    }
+
+    #[test]
+    fn version_move_body() -> Result<(), serde_json::Error> {
+        assert_eq!(
+            r#"{"position":"First"}"#,
+            serde_json::to_string(&PostVersionMoveBody::Position(VersionPosition::First))?
+        );
+        assert_eq!(
+            r#"{"after":"https://jira.example.com/rest/api/2/version/10001"}"#,
+            serde_json::to_string(&PostVersionMoveBody::After(
+                "https://jira.example.com/rest/api/2/version/10001".to_string()
+            ))?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn field_round_trip() -> Result<(), serde_json::Error> {
+        assert_round_trip::<Field>(
+            r#"{
+                "id": "customfield_10016",
+                "name": "Story Points",
+                "custom": true,
+                "orderable": true,
+                "navigable": true,
+                "searchable": true,
+                "clauseNames": ["cf[10016]"],
+                "schema": {
+                    "custom": "com.atlassian.jira.plugin.system.customfieldtypes:float",
+                    "customId": 10016,
+                    "items": null,
+                    "system": null,
+                    "type": "number"
+                }
+            }"#,
+        )
+    }
+
+    #[test]
+    fn transitions_round_trip() -> Result<(), serde_json::Error> {
+        assert_round_trip::<GetTransitionsBody>(
+            r#"{
+                "expand": "transitions",
+                "transitions": [{
+                    "id": "21",
+                    "name": "Done",
+                    "fields": {
+                        "resolution": {
+                            "required": true,
+                            "name": "Resolution",
+                            "operations": ["set"],
+                            "schema": {"type": "resolution", "items": "", "custom": "", "customId": 0, "system": "resolution"},
+                            "allowedValues": [
+                                {"self": "https://jira.example.com/rest/api/2/resolution/1", "name": "Fixed", "id": "1"},
+                                {"self": "https://jira.example.com/rest/api/2/customFieldOption/1", "value": "Yes", "id": "2"}
+                            ],
+                            "hasDefaultValue": false
+                        }
+                    }
+                }]
+            }"#,
+        )
+    }
+
     #[test]
     fn issuekey_tostring() {
         let key = String::from("JB-1");
         let issue = IssueKey(key.clone());
         assert_eq!(key, issue.to_string());
     }
+
+    #[test]
+    fn audit_record_page_deserialize() -> Result<(), serde_json::Error> {
+        let page: AuditRecordPage = serde_json::from_str(
+            r#"{"offset":0,"limit":1000,"total":1,"records":[{"id":1,"summary":"User created",
+            "remoteAddress":"192.168.1.1","authorKey":"administrator","created":"2014-03-19T18:45:42.100+0000",
+            "category":"user management","eventSource":"Jira Connect Plugin",
+            "objectItem":{"id":"usr","name":"user","typeName":"USER"},
+            "changedValues":[{"fieldName":"email","changedFrom":"user@atlassian.com","changedTo":"newuser@atlassian.com"}]}]}"#,
+        )?;
+        let record = &page.records[0];
+        assert_eq!(1, page.total);
+        assert_eq!(Some("administrator"), record.author_key.as_deref());
+        assert_eq!(1, record.changed_values.len());
+        assert!(record.associated_items.is_empty());
+        Ok(())
+    }
 }
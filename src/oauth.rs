@@ -0,0 +1,261 @@
+//! OAuth 2.0 (3LO) for Jira Cloud apps.
+//!
+//! Access tokens are refreshed when they expire or are rejected, and the rejected request is sent again.
+//! Atlassian rotates refresh tokens, persist the latest tokens with `JiraAPIClient::oauth2_tokens`.
+//!
+//! ```rust,no_run
+//! # async fn connect() -> Result<(), jira_issue_api::JiraClientError> {
+//! use jira_issue_api::oauth::{cloud_url, get_accessible_resources};
+//! use jira_issue_api::{Credential, JiraAPIClient, JiraClientConfig};
+//!
+//! let access_token = "access".to_string();
+//! let mut cfg = JiraClientConfig {
+//!     credential: Credential::OAuth2 {
+//!         access_token: access_token.clone(),
+//!         refresh_token: "refresh".to_string(),
+//!         client_id: "client id".to_string(),
+//!         client_secret: "client secret".to_string(),
+//!     },
+//!     ..Default::default()
+//! };
+//!
+//! let site = get_accessible_resources(&cfg, &access_token).await?.remove(0);
+//! cfg.url = cloud_url(&site.id);
+//! let client = JiraAPIClient::new(&cfg)?;
+//! # Ok(())
+//! # }
+//! ```
+use crate::{
+    client::{annotate_response, api_error, deserialize_response, now},
+    Credential, JiraAPIClient, JiraClientConfig, JiraClientError,
+};
+use reqwest::{header::HeaderValue, Client, Method};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{Debug, Formatter},
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime},
+};
+
+pub const TOKEN_URL: &str = "https://auth.atlassian.com/oauth/token";
+pub const ACCESSIBLE_RESOURCES_URL: &str =
+    "https://api.atlassian.com/oauth/token/accessible-resources";
+
+/// Refresh when the access token expires within this margin
+const REFRESH_MARGIN: Duration = Duration::from_secs(30);
+
+/// Base url of a Cloud site for OAuth 2.0 requests, which must go through the API gateway
+pub fn cloud_url(cloud_id: &str) -> String {
+    format!("https://api.atlassian.com/ex/jira/{}/", cloud_id)
+}
+
+/// A site the access token was granted for, id is the cloudId
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessibleResource {
+    pub id: String,
+    /// Site url, e.g. https://domain.atlassian.net
+    pub url: String,
+    pub name: String,
+    pub scopes: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar_url: Option<String>,
+}
+
+/// Sites the access token was granted for, connecting with the TLS, proxy and timeout of cfg.
+/// cfg.url is not used, so it can be set from the result.
+pub async fn get_accessible_resources(
+    cfg: &JiraClientConfig,
+    access_token: &str,
+) -> Result<Vec<AccessibleResource>, JiraClientError> {
+    let response = JiraAPIClient::build_client(cfg)?
+        .get(ACCESSIBLE_RESOURCES_URL)
+        .bearer_auth(access_token)
+        .send()
+        .await?;
+    if !response.status().is_success() {
+        return Err(api_error(response).await);
+    }
+    deserialize_response::<Vec<AccessibleResource>>(response).await
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct OAuth2Tokens {
+    pub access_token: String,
+    pub refresh_token: String,
+}
+
+impl Debug for OAuth2Tokens {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OAuth2Tokens")
+            .field("access_token", &"<redacted>")
+            .field("refresh_token", &"<redacted>")
+            .finish()
+    }
+}
+
+#[derive(Serialize)]
+struct PostRefreshBody<'a> {
+    grant_type: &'static str,
+    client_id: &'a str,
+    client_secret: &'a str,
+    refresh_token: &'a str,
+}
+
+#[derive(Deserialize)]
+struct RefreshResponseBody {
+    access_token: String,
+    /// Only returned when refresh tokens are rotated
+    refresh_token: Option<String>,
+    /// Seconds
+    expires_in: u64,
+}
+
+/// Current tokens of a client, shared by its clones
+pub(crate) struct OAuth2Session {
+    client_id: String,
+    client_secret: String,
+    /// Tokens and when the access token expires, unknown until the first refresh
+    tokens: Mutex<(OAuth2Tokens, Option<SystemTime>)>,
+    /// Refresh tokens are single use, so only one refresh may be in flight
+    refreshing: tokio::sync::Mutex<()>,
+}
+
+impl OAuth2Session {
+    pub(crate) fn from_credential(credential: &Credential) -> Option<Arc<OAuth2Session>> {
+        match credential {
+            Credential::OAuth2 {
+                access_token,
+                refresh_token,
+                client_id,
+                client_secret,
+            } => Some(Arc::new(OAuth2Session {
+                client_id: client_id.clone(),
+                client_secret: client_secret.clone(),
+                tokens: Mutex::new((
+                    OAuth2Tokens {
+                        access_token: access_token.clone(),
+                        refresh_token: refresh_token.clone(),
+                    },
+                    None,
+                )),
+                refreshing: tokio::sync::Mutex::new(()),
+            })),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn tokens(&self) -> OAuth2Tokens {
+        self.lock().0.clone()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, (OAuth2Tokens, Option<SystemTime>)> {
+        self.tokens
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Authorization header, refreshed first if the access token is about to expire
+    pub(crate) async fn header(&self, client: &Client) -> Result<HeaderValue, JiraClientError> {
+        let (tokens, expires) = self.lock().clone();
+        let access_token = match expires {
+            Some(expires) if now() + REFRESH_MARGIN >= expires => {
+                self.refresh(client, &tokens.access_token).await?
+            }
+            _ => tokens.access_token,
+        };
+        bearer(&access_token)
+    }
+
+    /// Refresh unless another request already replaced the rejected access token
+    pub(crate) async fn refresh(
+        &self,
+        client: &Client,
+        rejected: &str,
+    ) -> Result<String, JiraClientError> {
+        let _refreshing = self.refreshing.lock().await;
+        let tokens = self.tokens();
+        if tokens.access_token != rejected {
+            return Ok(tokens.access_token);
+        }
+
+        let body = PostRefreshBody {
+            grant_type: "refresh_token",
+            client_id: &self.client_id,
+            client_secret: &self.client_secret,
+            refresh_token: &tokens.refresh_token,
+        };
+        let mut response = client.post(TOKEN_URL).json(&body).send().await?;
+        annotate_response(&mut response, Method::POST, None);
+        if !response.status().is_success() {
+            return Err(api_error(response).await);
+        }
+        let refreshed = deserialize_response::<RefreshResponseBody>(response).await?;
+
+        let mut state = self.lock();
+        *state = (
+            OAuth2Tokens {
+                access_token: refreshed.access_token.clone(),
+                refresh_token: refreshed.refresh_token.unwrap_or(tokens.refresh_token),
+            },
+            Some(now() + Duration::from_secs(refreshed.expires_in)),
+        );
+        Ok(refreshed.access_token)
+    }
+}
+
+impl Debug for OAuth2Session {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OAuth2Session")
+            .field("client_id", &self.client_id)
+            .finish_non_exhaustive()
+    }
+}
+
+pub(crate) fn bearer(token: &str) -> Result<HeaderValue, JiraClientError> {
+    let mut header = HeaderValue::from_str(&format!("Bearer {}", token))
+        .map_err(|e| JiraClientError::ConfigError(format!("Invalid access token: {}", e)))?;
+    header.set_sensitive(true);
+    Ok(header)
+}
+
+/// Access token of a Bearer Authorization header
+pub(crate) fn bearer_token(header: &HeaderValue) -> Option<&str> {
+    header.to_str().ok()?.strip_prefix("Bearer ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cloud_url_joins_platform_api() -> Result<(), url::ParseError> {
+        let url = reqwest::Url::parse(&cloud_url("11223344-a1b2-3b33-c444-def123456789"))?;
+        assert_eq!(
+            "https://api.atlassian.com/ex/jira/11223344-a1b2-3b33-c444-def123456789/rest/api/3/myself",
+            url.join("rest/api/3/myself")?.as_str()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn tokens_are_shared_and_redacted() {
+        let session = OAuth2Session::from_credential(&Credential::OAuth2 {
+            access_token: "access".to_string(),
+            refresh_token: "refresh".to_string(),
+            client_id: "id".to_string(),
+            client_secret: "secret".to_string(),
+        })
+        .unwrap();
+
+        assert_eq!("access", session.tokens().access_token);
+        assert!(!format!("{:?}", session.tokens()).contains("\"access\""));
+        assert_eq!(
+            Some("access"),
+            bearer(&session.tokens().access_token)
+                .ok()
+                .as_ref()
+                .and_then(bearer_token)
+        );
+    }
+}
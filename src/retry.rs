@@ -0,0 +1,360 @@
+//! Retry of transient failures, e.g. connection resets and 503 during deployments,
+//! and of rate limited requests after their Retry-After delay.
+//!
+//! ```rust
+//! use jira_issue_api::retry::{RetryCondition, RetryPolicy};
+//! use jira_issue_api::JiraClientConfig;
+//! use std::{sync::Arc, time::Duration};
+//!
+//! let cfg = JiraClientConfig {
+//!     url: "https://jira.example.com".to_string(),
+//!     retry: RetryPolicy {
+//!         max_attempts: 5,
+//!         base_delay: Duration::from_secs(1),
+//!         retry_post: true,
+//!         retry_on: Arc::new(|condition| match condition {
+//!             RetryCondition::Status(status) => status.is_server_error(),
+//!             RetryCondition::Error(error) => error.is_connect() || error.is_timeout(),
+//!         }),
+//!         ..Default::default()
+//!     },
+//!     ..Default::default()
+//! };
+//! ```
+use reqwest::{header::HeaderMap, Client, Method, Request, Response, StatusCode};
+use std::{
+    collections::hash_map::RandomState,
+    fmt::{Debug, Formatter},
+    hash::{BuildHasher, Hasher},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// Outcome of a failed attempt, passed to RetryPolicy::retry_on
+#[derive(Debug)]
+pub enum RetryCondition<'a> {
+    /// The response status, never a success status
+    Status(StatusCode),
+    /// The request failed before a response was received
+    Error(&'a reqwest::Error),
+}
+
+/// Applied to GET, HEAD, PUT, DELETE and OPTIONS, and to POST when retry_post is set.
+/// Requests with streamed bodies, e.g. attachment uploads, are never retried, nor is anything on wasm.
+#[derive(Clone)]
+pub struct RetryPolicy {
+    /// Total attempts including the first, 1 disables retries
+    pub max_attempts: u32,
+    /// Delay before the first retry, doubled for every following retry
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    /// Randomize each delay between half and all of it, spreading retries of concurrent requests
+    pub jitter: bool,
+    /// POST is not idempotent, e.g. a retried create_issue may create the issue twice
+    pub retry_post: bool,
+    /// Whether a failed attempt is retried, defaults to connection errors, timeouts and 502/503/504
+    pub retry_on: Arc<dyn Fn(RetryCondition) -> bool + Send + Sync>,
+    /// Retries of 429 responses for any method, as rate limited requests are not processed
+    pub rate_limit_retries: u32,
+    /// 429 responses asking to wait longer than this are returned instead of retried
+    pub max_retry_after: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(10),
+            jitter: true,
+            retry_post: false,
+            retry_on: Arc::new(is_transient),
+            rate_limit_retries: 3,
+            max_retry_after: Duration::from_secs(60),
+        }
+    }
+}
+
+impl Debug for RetryPolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("base_delay", &self.base_delay)
+            .field("max_delay", &self.max_delay)
+            .field("jitter", &self.jitter)
+            .field("retry_post", &self.retry_post)
+            .field("rate_limit_retries", &self.rate_limit_retries)
+            .field("max_retry_after", &self.max_retry_after)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+impl RetryPolicy {
+    /// Fail on the first error
+    pub fn none() -> Self {
+        RetryPolicy {
+            max_attempts: 1,
+            rate_limit_retries: 0,
+            ..Default::default()
+        }
+    }
+
+    fn allows(&self, method: &Method) -> bool {
+        match *method {
+            Method::GET | Method::HEAD | Method::PUT | Method::DELETE | Method::OPTIONS => true,
+            Method::POST => self.retry_post,
+            _ => false,
+        }
+    }
+
+    /// Delay before retry number `retry`, starting at 1
+    fn delay(&self, retry: u32) -> Duration {
+        let delay = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(retry.saturating_sub(1)))
+            .min(self.max_delay);
+
+        match self.jitter {
+            true => delay / 2 + (delay / 2).mul_f64(random_fraction()),
+            false => delay,
+        }
+    }
+
+    /// Delay before retrying a rate limited response, None if it should be returned
+    fn rate_limit_delay(
+        &self,
+        result: &Result<Response, reqwest::Error>,
+        rate_limited: u32,
+    ) -> Option<Duration> {
+        let response = result.as_ref().ok()?;
+        if response.status() != StatusCode::TOO_MANY_REQUESTS
+            || rate_limited >= self.rate_limit_retries
+        {
+            return None;
+        }
+
+        match retry_after(response.headers()) {
+            Some(delay) if delay > self.max_retry_after => None,
+            Some(delay) => Some(delay),
+            None => Some(self.delay(rate_limited + 1)),
+        }
+    }
+
+    fn should_retry(&self, result: &Result<Response, reqwest::Error>) -> bool {
+        match result {
+            Ok(response) if response.status().is_success() => false,
+            Ok(response) => (self.retry_on)(RetryCondition::Status(response.status())),
+            Err(error) => (self.retry_on)(RetryCondition::Error(error)),
+        }
+    }
+}
+
+/// Default of RetryPolicy::retry_on
+pub fn is_transient(condition: RetryCondition) -> bool {
+    match condition {
+        RetryCondition::Status(status) => matches!(
+            status,
+            StatusCode::BAD_GATEWAY | StatusCode::SERVICE_UNAVAILABLE | StatusCode::GATEWAY_TIMEOUT
+        ),
+        #[cfg(not(target_arch = "wasm32"))]
+        RetryCondition::Error(error) => error.is_connect() || error.is_timeout(),
+        #[cfg(target_arch = "wasm32")]
+        RetryCondition::Error(error) => error.is_timeout(),
+    }
+}
+
+/// Rate limit headers of a response, Jira Cloud sends them on 429 and when close to a limit
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RateLimitInfo {
+    /// Retry-After, seconds to wait before retrying
+    pub retry_after: Option<Duration>,
+    /// X-RateLimit-Limit, requests allowed per window
+    pub limit: Option<u64>,
+    /// X-RateLimit-Remaining
+    pub remaining: Option<u64>,
+    /// X-RateLimit-Reset, ISO 8601 timestamp of when the window resets
+    pub reset: Option<String>,
+    /// X-RateLimit-NearLimit, less than 20% of the budget remains
+    pub near_limit: bool,
+    /// RateLimit-Reason, the limit that was exceeded
+    pub reason: Option<String>,
+}
+
+impl RateLimitInfo {
+    /// None if the response has no rate limit headers
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let header = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+
+        let info = RateLimitInfo {
+            retry_after: retry_after(headers),
+            limit: header("x-ratelimit-limit").and_then(|limit| limit.parse().ok()),
+            remaining: header("x-ratelimit-remaining").and_then(|remaining| remaining.parse().ok()),
+            reset: header("x-ratelimit-reset").map(str::to_string),
+            near_limit: header("x-ratelimit-nearlimit").is_some_and(|near| near == "true"),
+            reason: header("ratelimit-reason").map(str::to_string),
+        };
+        match info == RateLimitInfo::default() {
+            true => None,
+            false => Some(info),
+        }
+    }
+}
+
+/// Latest rate limit info, shared by clones of a client
+pub(crate) type RateLimitState = Arc<Mutex<Option<RateLimitInfo>>>;
+
+/// Retry-After in seconds, Jira does not send HTTP dates
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get("retry-after")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|seconds| seconds.trim().parse().ok())
+        .map(Duration::from_secs)
+}
+
+/// Uniform in [0, 1), RandomState is seeded randomly per instance
+#[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+fn random_fraction() -> f64 {
+    let random = RandomState::new().build_hasher().finish();
+    (random >> 11) as f64 / (1u64 << 53) as f64
+}
+
+fn record_rate_limit(rate_limit: &RateLimitState, result: &Result<Response, reqwest::Error>) {
+    if let Some(info) = result
+        .as_ref()
+        .ok()
+        .and_then(|response| RateLimitInfo::from_headers(response.headers()))
+    {
+        *rate_limit.lock().unwrap_or_else(|e| e.into_inner()) = Some(info);
+    }
+}
+
+/// Execute the request, retrying as allowed by the policy and recording rate limit headers
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn execute(
+    client: &Client,
+    policy: &RetryPolicy,
+    rate_limit: &RateLimitState,
+    mut request: Request,
+) -> Result<Response, reqwest::Error> {
+    let allowed = policy.allows(request.method());
+
+    let mut retry = 1;
+    let mut rate_limited = 0;
+    loop {
+        let retryable = allowed && retry < policy.max_attempts;
+        let next = match retryable || rate_limited < policy.rate_limit_retries {
+            true => request.try_clone(),
+            false => None,
+        };
+
+        let result = client.execute(request).await;
+        record_rate_limit(rate_limit, &result);
+
+        let Some(next) = next else {
+            return result;
+        };
+        if let Some(delay) = policy.rate_limit_delay(&result, rate_limited) {
+            tokio::time::sleep(delay).await;
+            rate_limited += 1;
+        } else if retryable && policy.should_retry(&result) {
+            tokio::time::sleep(policy.delay(retry)).await;
+            retry += 1;
+        } else {
+            return result;
+        }
+        request = next;
+    }
+}
+
+/// The wasm backend has no timer to wait between attempts, so requests are sent once
+#[cfg(target_arch = "wasm32")]
+pub(crate) async fn execute(
+    client: &Client,
+    _policy: &RetryPolicy,
+    rate_limit: &RateLimitState,
+    request: Request,
+) -> Result<Response, reqwest::Error> {
+    let result = client.execute(request).await;
+    record_rate_limit(rate_limit, &result);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exponential_delay_capped() {
+        let policy = RetryPolicy {
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(300),
+            jitter: false,
+            ..Default::default()
+        };
+        assert_eq!(Duration::from_millis(100), policy.delay(1));
+        assert_eq!(Duration::from_millis(200), policy.delay(2));
+        assert_eq!(Duration::from_millis(300), policy.delay(3));
+        assert_eq!(Duration::from_millis(300), policy.delay(40));
+    }
+
+    #[test]
+    fn jitter_within_half_delay() {
+        let policy = RetryPolicy {
+            base_delay: Duration::from_millis(100),
+            ..Default::default()
+        };
+        for _ in 0..100 {
+            let delay = policy.delay(1);
+            assert!(delay >= Duration::from_millis(50) && delay <= Duration::from_millis(100));
+        }
+    }
+
+    #[test]
+    fn post_only_retried_when_opted_in() {
+        let policy = RetryPolicy::default();
+        assert!(policy.allows(&Method::GET));
+        assert!(policy.allows(&Method::PUT));
+        assert!(!policy.allows(&Method::POST));
+        assert!(RetryPolicy {
+            retry_post: true,
+            ..Default::default()
+        }
+        .allows(&Method::POST));
+    }
+
+    #[test]
+    fn rate_limit_info_from_headers() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(None, RateLimitInfo::from_headers(&headers));
+
+        headers.insert("Retry-After", "5".parse().unwrap());
+        headers.insert("X-RateLimit-Limit", "100".parse().unwrap());
+        headers.insert("X-RateLimit-Remaining", "0".parse().unwrap());
+        headers.insert("X-RateLimit-NearLimit", "true".parse().unwrap());
+        headers.insert("RateLimit-Reason", "jira-burst-based".parse().unwrap());
+        assert_eq!(
+            Some(RateLimitInfo {
+                retry_after: Some(Duration::from_secs(5)),
+                limit: Some(100),
+                remaining: Some(0),
+                reset: None,
+                near_limit: true,
+                reason: Some("jira-burst-based".to_string()),
+            }),
+            RateLimitInfo::from_headers(&headers)
+        );
+    }
+
+    #[test]
+    fn transient_statuses() {
+        assert!(is_transient(RetryCondition::Status(
+            StatusCode::SERVICE_UNAVAILABLE
+        )));
+        assert!(!is_transient(RetryCondition::Status(
+            StatusCode::INTERNAL_SERVER_ERROR
+        )));
+        assert!(!is_transient(RetryCondition::Status(StatusCode::NOT_FOUND)));
+    }
+}
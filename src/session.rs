@@ -0,0 +1,302 @@
+//! Session (cookie) authentication for Data Center instances with basic auth disabled.
+//!
+//! The client logs in before its first request and again when the session expires.
+//! The session cookie is kept per credential, clients derived with another credential never send it.
+//! On wasm the browser keeps the cookies instead.
+//!
+//! ```rust,no_run
+//! # async fn session() -> Result<(), jira_issue_api::JiraClientError> {
+//! use jira_issue_api::{Credential, JiraAPIClient, JiraClientConfig};
+//!
+//! let cfg = JiraClientConfig {
+//!     credential: Credential::Session {
+//!         username: "jdoe".to_string(),
+//!         password: "secret".to_string(),
+//!     },
+//!     url: "https://jira.example.com".to_string(),
+//!     ..Default::default()
+//! };
+//! let client = JiraAPIClient::new(&cfg)?;
+//! // client.get_myself().await
+//! client.logout().await?;
+//! # Ok(())
+//! # }
+//! ```
+use crate::{
+    access::Access,
+    client::{annotate_response, api_error, deserialize_response},
+    deployment::{Deployment, DeploymentType},
+    Credential, JiraAPIClient, JiraClientError,
+};
+#[cfg(not(target_arch = "wasm32"))]
+use reqwest::{
+    cookie::{CookieStore, Jar},
+    header::{COOKIE, SET_COOKIE},
+};
+use reqwest::{Method, Request, Response, Url};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{Debug, Formatter},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionLogin {
+    pub session: SessionCookie,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub login_info: Option<LoginInfo>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct SessionCookie {
+    /// e.g. JSESSIONID
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LoginInfo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failed_login_count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub login_count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_failed_login_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_login_time: Option<String>,
+}
+
+#[derive(Serialize)]
+struct PostSessionBody<'a> {
+    username: &'a str,
+    password: &'a str,
+}
+
+/// Login state of a client, shared by its clones
+pub(crate) struct SessionAuth {
+    username: String,
+    password: String,
+    logged_in: AtomicBool,
+    /// Concurrent requests wait for a single login
+    logging_in: tokio::sync::Mutex<()>,
+    #[cfg(not(target_arch = "wasm32"))]
+    cookies: Jar,
+}
+
+impl SessionAuth {
+    /// Fails when creating the client for deployments without session login
+    pub(crate) fn from_credential(
+        credential: &Credential,
+        deployment: DeploymentType,
+    ) -> Result<Option<Arc<SessionAuth>>, JiraClientError> {
+        match credential {
+            Credential::Session { .. } if deployment == DeploymentType::Cloud => {
+                Err(JiraClientError::ConfigError(
+                    "Credential::Session is only supported on Data Center".to_string(),
+                ))
+            }
+            Credential::Session { username, .. } if username.is_empty() => Err(
+                JiraClientError::ConfigError("Credential::Session without username".to_string()),
+            ),
+            Credential::Session { username, password } => Ok(Some(Arc::new(SessionAuth {
+                username: username.clone(),
+                password: password.clone(),
+                logged_in: AtomicBool::new(false),
+                logging_in: tokio::sync::Mutex::new(()),
+                #[cfg(not(target_arch = "wasm32"))]
+                cookies: Jar::default(),
+            }))),
+            _ => Ok(None),
+        }
+    }
+
+    pub(crate) fn is_logged_in(&self) -> bool {
+        self.logged_in.load(Ordering::Acquire)
+    }
+
+    /// Send the cookies of this session with the request
+    pub(crate) fn add_cookies(&self, request: &mut Request) {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(cookies) = self.cookies.cookies(request.url()) {
+            request.headers_mut().insert(COOKIE, cookies);
+        }
+        #[cfg(target_arch = "wasm32")]
+        let _ = request;
+    }
+
+    /// Keep the cookies set by the response, e.g. the session cookie set by login
+    pub(crate) fn store_cookies(&self, response: &Response) {
+        #[cfg(not(target_arch = "wasm32"))]
+        self.cookies.set_cookies(
+            &mut response.headers().get_all(SET_COOKIE).iter(),
+            response.url(),
+        );
+        #[cfg(target_arch = "wasm32")]
+        let _ = response;
+    }
+}
+
+impl Debug for SessionAuth {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SessionAuth")
+            .field("username", &self.username)
+            .field("password", &"<redacted>")
+            .field("logged_in", &self.is_logged_in())
+            .finish()
+    }
+}
+
+impl<D: Deployment, A: Access> JiraAPIClient<D, A> {
+    fn session_url(&self) -> Result<Url, JiraClientError> {
+        Ok(self.url.join("rest/auth/1/session")?)
+    }
+
+    /// Log in with Credential::Session, requests log in automatically so this is only needed
+    /// to check the credential upfront.
+    pub async fn login(&self) -> Result<SessionLogin, JiraClientError> {
+        let Some(session) = &self.session else {
+            return Err(JiraClientError::ConfigError(
+                "login requires Credential::Session".to_string(),
+            ));
+        };
+
+        let _logging_in = session.logging_in.lock().await;
+        self.create_session(session).await
+    }
+
+    /// Log in unless already logged in, concurrent first requests share a single login
+    pub(crate) async fn ensure_session(
+        &self,
+        session: &SessionAuth,
+    ) -> Result<(), JiraClientError> {
+        if session.is_logged_in() {
+            return Ok(());
+        }
+        let _logging_in = session.logging_in.lock().await;
+        if !session.is_logged_in() {
+            self.create_session(session).await?;
+        }
+        Ok(())
+    }
+
+    async fn create_session(&self, session: &SessionAuth) -> Result<SessionLogin, JiraClientError> {
+        session.logged_in.store(false, Ordering::Release);
+        let url = self.session_url()?;
+
+        let body = PostSessionBody {
+            username: &session.username,
+            password: &session.password,
+        };
+        let mut response = self
+            .execute(self.client.post(url).json(&body).build()?)
+            .await?;
+        annotate_response(&mut response, Method::POST, None);
+        if !response.status().is_success() {
+            return Err(api_error(response).await);
+        }
+        let body = deserialize_response::<SessionLogin>(response).await?;
+
+        session.logged_in.store(true, Ordering::Release);
+        Ok(body)
+    }
+
+    /// End the session, the next request logs in again
+    pub async fn logout(&self) -> Result<(), JiraClientError> {
+        match &self.session {
+            Some(session) if session.is_logged_in() => {
+                let url = self.session_url()?;
+
+                self.send(self.client.delete(url)).await?;
+                session.logged_in.store(false, Ordering::Release);
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(not(target_arch = "wasm32"))]
+    use reqwest::ResponseBuilderExt;
+
+    #[test]
+    fn session_login_deserialize() -> Result<(), serde_json::Error> {
+        let login = serde_json::from_str::<SessionLogin>(
+            r#"{
+                "session": {"name": "JSESSIONID", "value": "6E3487971234567896704A9EB4AE501F"},
+                "loginInfo": {"failedLoginCount": 1, "loginCount": 2, "previousLoginTime": "2024-01-01T09:00:00.000+0000"}
+            }"#,
+        )?;
+        assert_eq!("JSESSIONID", login.session.name);
+        assert_eq!(Some(2), login.login_info.and_then(|info| info.login_count));
+        Ok(())
+    }
+
+    #[test]
+    fn session_auth_redacts_password() {
+        let session = SessionAuth::from_credential(
+            &Credential::Session {
+                username: "jdoe".to_string(),
+                password: "secret".to_string(),
+            },
+            DeploymentType::DataCenter,
+        )
+        .unwrap()
+        .unwrap();
+        assert!(!session.is_logged_in());
+        assert!(!format!("{:?}", session).contains("secret"));
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn derived_sessions_do_not_share_cookies() -> Result<(), JiraClientError> {
+        let client = JiraAPIClient::new(&crate::JiraClientConfig {
+            credential: Credential::Session {
+                username: "jdoe".to_string(),
+                password: "secret".to_string(),
+            },
+            url: "https://jira.example.com".to_string(),
+            deployment: DeploymentType::DataCenter,
+            ..Default::default()
+        })?;
+        let other = client.with_credential(Credential::Session {
+            username: "asmith".to_string(),
+            password: "secret".to_string(),
+        })?;
+        let url = client.url.join("rest/api/latest/myself")?;
+
+        let login = http::Response::builder()
+            .url(client.session_url()?)
+            .header(SET_COOKIE, "JSESSIONID=jdoe-session; Path=/")
+            .body("")
+            .unwrap();
+        client
+            .session
+            .as_ref()
+            .unwrap()
+            .store_cookies(&Response::from(login));
+
+        let cookie = |client: &JiraAPIClient| {
+            let mut request = Request::new(Method::GET, url.clone());
+            client.session.as_ref().unwrap().add_cookies(&mut request);
+            request.headers().get(COOKIE).cloned()
+        };
+        assert_eq!(
+            Some("JSESSIONID=jdoe-session"),
+            cookie(&client).as_ref().and_then(|c| c.to_str().ok())
+        );
+        assert_eq!(None, cookie(&other));
+        assert!(client
+            .with_credential(Credential::PersonalAccessToken("token".to_string()))?
+            .session
+            .is_none());
+        Ok(())
+    }
+}
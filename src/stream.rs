@@ -0,0 +1,350 @@
+use crate::{
+    client::{truncate_body, MaxResponseSize},
+    JiraClientError, RequestContext,
+};
+use futures_util::{Stream, StreamExt};
+use reqwest::Response;
+use serde::de::{DeserializeOwned, Error as _};
+use std::{
+    any::type_name,
+    sync::{Arc, Mutex},
+};
+
+/// Incrementally splits the elements out of a JSON array, either the body itself
+/// or an array field of the top level object, e.g. "issues" of a search response.
+///
+/// Bytes are dropped as elements are split, so only one element is buffered at a time.
+#[derive(Debug)]
+pub(crate) struct JsonArraySplitter {
+    field: Option<&'static str>,
+    buffer: Vec<u8>,
+    /// Scan position in buffer
+    position: usize,
+    depth: usize,
+    in_string: bool,
+    escaped: bool,
+    /// Start of the last string seen before the array was found
+    string_start: usize,
+    last_string: Vec<u8>,
+    array_depth: Option<usize>,
+    element_start: Option<usize>,
+    done: bool,
+    /// Bytes before the array up to its opening bracket
+    prefix: Vec<u8>,
+}
+
+impl JsonArraySplitter {
+    pub(crate) fn new(field: Option<&'static str>) -> JsonArraySplitter {
+        JsonArraySplitter {
+            field,
+            buffer: Vec::new(),
+            position: 0,
+            depth: 0,
+            in_string: false,
+            escaped: false,
+            string_start: 0,
+            last_string: Vec::new(),
+            array_depth: None,
+            element_start: None,
+            done: false,
+            prefix: Vec::new(),
+        }
+    }
+
+    pub(crate) fn push(&mut self, chunk: &[u8]) {
+        self.buffer.extend_from_slice(chunk);
+    }
+
+    /// The closing bracket of the array has been reached
+    pub(crate) fn is_done(&self) -> bool {
+        self.done
+    }
+
+    /// Unprocessed bytes, the whole body if the array was never found
+    pub(crate) fn remaining(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    /// The body with an empty array, e.g. {"total":2,"issues":[]}, once the rest of the body is pushed
+    pub(crate) fn envelope(&self) -> Vec<u8> {
+        let mut envelope = self.prefix.clone();
+        envelope.push(b']');
+        envelope.extend_from_slice(&self.buffer);
+        envelope
+    }
+
+    fn is_target_array(&self) -> bool {
+        match self.field {
+            None => self.depth == 1,
+            Some(field) => self.depth == 2 && self.last_string == field.as_bytes(),
+        }
+    }
+
+    fn consume(&mut self) {
+        self.buffer.drain(..self.position);
+        self.position = 0;
+    }
+
+    /// Next complete element, None if more bytes are needed or the array is done.
+    pub(crate) fn next_element(&mut self) -> Option<Vec<u8>> {
+        while !self.done && self.position < self.buffer.len() {
+            let i = self.position;
+            let byte = self.buffer[i];
+            self.position += 1;
+
+            if self.in_string {
+                match byte {
+                    _ if self.escaped => self.escaped = false,
+                    b'\\' => self.escaped = true,
+                    b'"' => {
+                        self.in_string = false;
+                        if self.array_depth.is_none() {
+                            self.last_string = self.buffer[self.string_start..i].to_vec();
+                        }
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+
+            let Some(array_depth) = self.array_depth else {
+                match byte {
+                    b'"' => {
+                        self.in_string = true;
+                        self.string_start = i + 1;
+                    }
+                    b'{' => self.depth += 1,
+                    b'[' => {
+                        self.depth += 1;
+                        if self.is_target_array() {
+                            self.array_depth = Some(self.depth);
+                            if self.field.is_some() {
+                                self.prefix = self.buffer[..self.position].to_vec();
+                            }
+                            self.consume();
+                        }
+                    }
+                    b'}' | b']' => self.depth = self.depth.saturating_sub(1),
+                    _ => {}
+                }
+                continue;
+            };
+
+            match byte {
+                b',' | b']' if self.depth == array_depth => {
+                    let element = self
+                        .element_start
+                        .take()
+                        .map(|start| self.buffer[start..i].to_vec());
+                    self.done = byte == b']';
+                    self.consume();
+
+                    if element.is_some() {
+                        return element;
+                    }
+                }
+                b'{' | b'[' => {
+                    if self.depth == array_depth {
+                        self.element_start.get_or_insert(i);
+                    }
+                    self.depth += 1;
+                }
+                b'}' | b']' => self.depth -= 1,
+                _ if byte.is_ascii_whitespace() => {}
+                _ => {
+                    if byte == b'"' {
+                        self.in_string = true;
+                    }
+                    if self.depth == array_depth {
+                        self.element_start.get_or_insert(i);
+                    }
+                }
+            }
+        }
+
+        if self.array_depth.is_some() && self.element_start.is_none() {
+            self.consume();
+        }
+        None
+    }
+}
+
+/// Set to the envelope of the array once the stream has ended, see JsonArraySplitter::envelope
+pub(crate) type Envelope = Arc<Mutex<Option<Vec<u8>>>>;
+
+/// Deserialize the elements of an array field from the response body as they arrive,
+/// then read the rest of the body for the envelope, e.g. total or nextPageToken of a search page
+pub(crate) fn stream_page<T: DeserializeOwned>(
+    response: Response,
+    field: &'static str,
+) -> (impl Stream<Item = Result<T, JiraClientError>>, Envelope) {
+    let envelope = Envelope::default();
+    (
+        stream_elements(response, Some(field), Some(envelope.clone())),
+        envelope,
+    )
+}
+
+/// Deserialize the elements of a top level array from the response body as they arrive
+pub(crate) fn stream_array<T: DeserializeOwned>(
+    response: Response,
+) -> impl Stream<Item = Result<T, JiraClientError>> {
+    stream_elements(response, None, None)
+}
+
+/// Deserialize array elements from the response body as they arrive.
+fn stream_elements<T: DeserializeOwned>(
+    response: Response,
+    field: Option<&'static str>,
+    envelope: Option<Envelope>,
+) -> impl Stream<Item = Result<T, JiraClientError>> {
+    let context = RequestContext::from_response(&response);
+    let limit = MaxResponseSize::from_response(&response);
+    let content_length = response.content_length();
+    let bytes = Box::pin(response.bytes_stream());
+    let splitter = JsonArraySplitter::new(field);
+
+    futures_util::stream::unfold(
+        (bytes, splitter, 0u64, false),
+        move |(mut bytes, mut splitter, mut received, finished)| {
+            let context = context.clone();
+            let envelope = envelope.clone();
+            async move {
+                if finished {
+                    return None;
+                }
+
+                if let Err(err) =
+                    MaxResponseSize::check(limit, content_length.unwrap_or(0), &context)
+                {
+                    return Some((Err(err), (bytes, splitter, received, true)));
+                }
+
+                loop {
+                    if let Some(element) = splitter.next_element() {
+                        let item = serde_json::from_slice::<T>(&element).map_err(|source| {
+                            JiraClientError::JiraResponseDeserializeError {
+                                context,
+                                target: type_name::<T>(),
+                                source,
+                                body: truncate_body(String::from_utf8_lossy(&element).into_owned()),
+                            }
+                        });
+                        return Some((item, (bytes, splitter, received, false)));
+                    }
+
+                    if splitter.is_done() && envelope.is_none() {
+                        return None;
+                    }
+
+                    match bytes.next().await {
+                        Some(Ok(chunk)) => {
+                            received += chunk.len() as u64;
+                            if let Err(err) = MaxResponseSize::check(limit, received, &context) {
+                                return Some((Err(err), (bytes, splitter, received, true)));
+                            }
+                            splitter.push(&chunk);
+                        }
+                        Some(Err(source)) => {
+                            let err = JiraClientError::RequestError { context, source };
+                            return Some((Err(err), (bytes, splitter, received, true)));
+                        }
+                        None if splitter.is_done() => {
+                            if let Some(envelope) = envelope {
+                                *envelope.lock().unwrap_or_else(|e| e.into_inner()) =
+                                    Some(splitter.envelope());
+                            }
+                            return None;
+                        }
+                        None => {
+                            let err = JiraClientError::JiraResponseDeserializeError {
+                                context,
+                                target: type_name::<T>(),
+                                source: serde_json::Error::custom(
+                                    "body ended before the array was closed",
+                                ),
+                                body: truncate_body(
+                                    String::from_utf8_lossy(splitter.remaining()).into_owned(),
+                                ),
+                            };
+                            return Some((Err(err), (bytes, splitter, received, true)));
+                        }
+                    }
+                }
+            }
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split_all(field: Option<&'static str>, body: &str, chunk_size: usize) -> Vec<String> {
+        let mut splitter = JsonArraySplitter::new(field);
+        let mut elements = Vec::new();
+
+        for chunk in body.as_bytes().chunks(chunk_size) {
+            splitter.push(chunk);
+            while let Some(element) = splitter.next_element() {
+                elements.push(String::from_utf8(element).unwrap().trim().to_string());
+            }
+        }
+        assert!(splitter.is_done());
+        elements
+    }
+
+    #[test]
+    fn splitter_field_array_any_chunk_size() {
+        let body = r#"{"expand":"names","names":{"issues":"[x]"},"startAt":0,"issues":[{"key":"JB-1","fields":{"labels":["a","]"]}}, {"key":"JB-2","summary":"\"{"}],"total":2}"#;
+
+        for chunk_size in 1..body.len() {
+            assert_eq!(
+                vec![
+                    r#"{"key":"JB-1","fields":{"labels":["a","]"]}}"#,
+                    r#"{"key":"JB-2","summary":"\"{"}"#,
+                ],
+                split_all(Some("issues"), body, chunk_size)
+            );
+        }
+    }
+
+    #[test]
+    fn splitter_top_level_array_with_scalars() {
+        let body = r#"[ 1, "two", [3], {"four": 4}, null ]"#;
+        assert_eq!(
+            vec!["1", r#""two""#, "[3]", r#"{"four": 4}"#, "null"],
+            split_all(None, body, 3)
+        );
+    }
+
+    #[test]
+    fn splitter_envelope_without_array() {
+        let body = r#"{"startAt":0,"total":2,"issues":[{"key":"JB-1"},{"key":"JB-2"}],"nextPageToken":"abc"}"#;
+        let mut splitter = JsonArraySplitter::new(Some("issues"));
+        for chunk in body.as_bytes().chunks(5) {
+            splitter.push(chunk);
+            while splitter.next_element().is_some() {}
+        }
+        assert_eq!(
+            r#"{"startAt":0,"total":2,"issues":[],"nextPageToken":"abc"}"#,
+            String::from_utf8(splitter.envelope()).unwrap()
+        );
+    }
+
+    #[test]
+    fn splitter_empty_array() {
+        assert!(split_all(Some("issues"), r#"{"issues":[],"total":0}"#, 4).is_empty());
+    }
+
+    #[test]
+    fn splitter_missing_field_keeps_body() {
+        let body = r#"{"errorMessages":["Field 'x' does not exist"],"errors":{}}"#;
+        let mut splitter = JsonArraySplitter::new(Some("issues"));
+        splitter.push(body.as_bytes());
+
+        assert!(splitter.next_element().is_none());
+        assert!(!splitter.is_done());
+        assert_eq!(body.as_bytes(), splitter.remaining());
+    }
+}
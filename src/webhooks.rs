@@ -0,0 +1,497 @@
+//! Webhook registration, the dynamic webhook API on Cloud and /rest/webhooks/1.0 on Data Center
+//!
+//! Cloud dynamic webhooks can only be registered by Connect and OAuth 2.0 apps
+//! and expire after 30 days unless refreshed with `refresh_webhooks`.
+//!
+//! ```rust,no_run
+//! # async fn register(client: jira_issue_api::JiraAPIClient) -> Result<(), jira_issue_api::JiraClientError> {
+//! use jira_issue_api::webhooks::WebhookDetails;
+//!
+//! let webhook = WebhookDetails::new("project = JB", &["jira:issue_created", "jira:issue_updated"]);
+//! let registered = client
+//!     .register_webhooks("https://example.com/jira/webhook", &[webhook])
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+use crate::{
+    access::{Access, Writable},
+    client::deserialize_response,
+    deployment::{CloudDeployment, Deployment},
+    models::{
+        ChangelogItem, Comment, FailedWebhook, GetFailedWebhooksResponseBody, Issue,
+        PutWebhookRefreshBody, PutWebhookRefreshResponseBody, User, Worklog,
+    },
+    JiraAPIClient, JiraClientError,
+};
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Data Center filter key for the JQL of issue related events
+const DC_JQL_FILTER_KEY: &str = "issue-related-events-section";
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookDetails {
+    /// Only issues matching the JQL trigger the webhook, Cloud supports a subset of JQL
+    pub jql_filter: String,
+    /// e.g. jira:issue_created, jira:issue_updated, comment_created
+    pub events: Vec<String>,
+    /// Cloud only, jira:issue_updated only fires when one of these fields changes
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub field_ids_filter: Vec<String>,
+    /// Data Center only, defaults to the webhook url
+    #[serde(skip)]
+    pub name: Option<String>,
+}
+
+impl WebhookDetails {
+    pub fn new(jql_filter: impl Into<String>, events: &[&str]) -> Self {
+        WebhookDetails {
+            jql_filter: jql_filter.into(),
+            events: events.iter().map(|event| event.to_string()).collect(),
+            field_ids_filter: Vec::new(),
+            name: None,
+        }
+    }
+}
+
+/// Result of registering a single webhook, in the order of the request
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RegisteredWebhook {
+    /// None if registration failed
+    #[serde(rename = "createdWebhookId", skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    #[serde(default)]
+    pub errors: Vec<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Webhook {
+    pub id: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jql_filter: Option<String>,
+    pub events: Vec<String>,
+    /// Cloud only, milliseconds since epoch
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration_date: Option<i64>,
+    /// Data Center only, Cloud webhooks share the url of the app
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Data Center only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Data Center only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+}
+
+/// Incoming webhook payload related types, deserialize the request body of a webhook delivery
+///
+/// ```rust
+/// use jira_issue_api::webhooks::WebhookEvent;
+///
+/// let body = r#"{"timestamp": 1704099600000, "webhookEvent": "jira:version_released"}"#;
+/// let event = serde_json::from_str::<WebhookEvent>(body).unwrap();
+/// assert!(matches!(event, WebhookEvent::Other));
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(tag = "webhookEvent")]
+pub enum WebhookEvent {
+    #[serde(rename = "jira:issue_created")]
+    IssueCreated(IssueEvent),
+    #[serde(rename = "jira:issue_updated")]
+    IssueUpdated(IssueEvent),
+    #[serde(rename = "jira:issue_deleted")]
+    IssueDeleted(IssueEvent),
+    #[serde(rename = "comment_created")]
+    CommentCreated(CommentEvent),
+    #[serde(rename = "comment_updated")]
+    CommentUpdated(CommentEvent),
+    #[serde(rename = "comment_deleted")]
+    CommentDeleted(CommentEvent),
+    #[serde(rename = "worklog_created")]
+    WorklogCreated(WorklogEvent),
+    #[serde(rename = "worklog_updated")]
+    WorklogUpdated(WorklogEvent),
+    #[serde(rename = "worklog_deleted")]
+    WorklogDeleted(WorklogEvent),
+    /// Any event without a typed payload, e.g. project, version or sprint events
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueEvent {
+    /// Milliseconds since epoch
+    pub timestamp: i64,
+    /// e.g. issue_generic, issue_assigned or issue_commented
+    #[serde(
+        rename = "issue_event_type_name",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub issue_event_type_name: Option<String>,
+    /// The user that triggered the event
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+    pub issue: Issue,
+    /// Some on jira:issue_updated when fields changed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changelog: Option<WebhookChangelog>,
+    /// Some on jira:issue_updated when the update added a comment
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<Comment>,
+}
+
+/// Unlike ChangelogHistory, author and created are those of the event
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookChangelog {
+    pub id: String,
+    pub items: Vec<ChangelogItem>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentEvent {
+    /// Milliseconds since epoch
+    pub timestamp: i64,
+    pub comment: Comment,
+    /// Cloud only, with a subset of the issue fields
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issue: Option<Issue>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WorklogEvent {
+    /// Milliseconds since epoch
+    pub timestamp: i64,
+    pub worklog: Worklog,
+}
+
+#[derive(Serialize, Debug, Clone)]
+struct PostWebhooksBody<'a> {
+    url: &'a str,
+    webhooks: &'a [WebhookDetails],
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+struct PostWebhooksResponseBody {
+    webhook_registration_result: Vec<RegisteredWebhook>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+struct WebhookPage {
+    is_last: bool,
+    values: Vec<Webhook>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+struct PostDataCenterWebhookBody<'a> {
+    name: &'a str,
+    url: &'a str,
+    events: &'a [String],
+    filters: BTreeMap<&'static str, &'a str>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+struct DataCenterWebhook {
+    #[serde(rename = "self")]
+    self_ref: String,
+    name: String,
+    url: String,
+    events: Vec<String>,
+    #[serde(default)]
+    filters: BTreeMap<String, String>,
+    enabled: Option<bool>,
+}
+
+impl TryFrom<DataCenterWebhook> for Webhook {
+    type Error = JiraClientError;
+    fn try_from(mut value: DataCenterWebhook) -> Result<Self, Self::Error> {
+        // Data Center webhooks are only identified by the last segment of their url
+        let id = value
+            .self_ref
+            .rsplit('/')
+            .next()
+            .and_then(|id| id.parse::<i64>().ok())
+            .ok_or_else(|| {
+                JiraClientError::TryFromError(format!("Malformed webhook url: {}", value.self_ref))
+            })?;
+
+        Ok(Webhook {
+            id,
+            jql_filter: value
+                .filters
+                .remove(DC_JQL_FILTER_KEY)
+                .filter(|jql| !jql.is_empty()),
+            events: value.events,
+            expiration_date: None,
+            url: Some(value.url),
+            name: Some(value.name),
+            enabled: value.enabled,
+        })
+    }
+}
+
+impl<D: Deployment, A: Access> JiraAPIClient<D, A> {
+    /// Data Center webhooks live outside the platform API
+    fn webhooks_url(&self, path: &str) -> Result<Url, JiraClientError> {
+        Ok(self.url.join(&format!("rest/webhooks/1.0/{}", path))?)
+    }
+
+    /// Webhooks registered by the app on Cloud, all webhooks on Data Center
+    pub async fn list_webhooks(&self) -> Result<Vec<Webhook>, JiraClientError> {
+        if !self.is_cloud() {
+            let url = self.webhooks_url("webhook")?;
+
+            let response = self.send(self.client.get(url)).await?;
+            let body = deserialize_response::<Vec<DataCenterWebhook>>(response).await?;
+            return body.into_iter().map(Webhook::try_from).collect();
+        }
+
+        let mut webhooks = Vec::new();
+        loop {
+            let url = self.api_url("webhook")?;
+
+            let params = [
+                ("startAt", webhooks.len().to_string()),
+                ("maxResults", self.max_results.to_string()),
+            ];
+            let response = self.send(self.client.get(url).query(&params)).await?;
+            let page = deserialize_response::<WebhookPage>(response).await?;
+
+            let done = page.is_last || page.values.is_empty();
+            webhooks.extend(page.values);
+            if done {
+                return Ok(webhooks);
+            }
+        }
+    }
+}
+
+impl<D: CloudDeployment, A: Access> JiraAPIClient<D, A> {
+    /// Failed webhook deliveries are kept for 72 hours.
+    /// after: milliseconds since epoch, only return failures after this time.
+    /// Returns a single page, pass next_after of the response for the next page or see get_all_failed_webhooks.
+    pub async fn get_failed_webhooks(
+        &self,
+        after: Option<i64>,
+    ) -> Result<GetFailedWebhooksResponseBody, JiraClientError> {
+        let mut url = self.api_url("webhook/failed")?;
+        let query = if let Some(after) = after {
+            format!("maxResults={}&after={}", self.max_results, after)
+        } else {
+            format!("maxResults={}", self.max_results)
+        };
+
+        url.set_query(Some(&query));
+
+        let response = self.send(self.client.get(url)).await?;
+        let body = deserialize_response::<GetFailedWebhooksResponseBody>(response).await?;
+        Ok(body)
+    }
+
+    /// Follows next until every failed delivery after the time is fetched
+    pub async fn get_all_failed_webhooks(
+        &self,
+        after: Option<i64>,
+    ) -> Result<Vec<FailedWebhook>, JiraClientError> {
+        let mut failed = Vec::new();
+        let mut after = after;
+        loop {
+            let page = self.get_failed_webhooks(after).await?;
+            let next = page.next_after();
+            let done = page.values.is_empty() || next.is_none() || next == after;
+            failed.extend(page.values);
+            if done {
+                return Ok(failed);
+            }
+            after = next;
+        }
+    }
+}
+
+impl<D: Deployment, A: Writable> JiraAPIClient<D, A> {
+    /// Register webhooks calling url. Cloud registers all webhooks in one request and
+    /// reports errors per webhook, Data Center registers one at a time and fails on the first error.
+    pub async fn register_webhooks(
+        &self,
+        url: &str,
+        webhooks: &[WebhookDetails],
+    ) -> Result<Vec<RegisteredWebhook>, JiraClientError> {
+        if self.is_cloud() {
+            let request_url = self.api_url("webhook")?;
+
+            let body = PostWebhooksBody { url, webhooks };
+            let response = self.send(self.client.post(request_url).json(&body)).await?;
+            let body = deserialize_response::<PostWebhooksResponseBody>(response).await?;
+            return Ok(body.webhook_registration_result);
+        }
+
+        let mut registered = Vec::with_capacity(webhooks.len());
+        for webhook in webhooks {
+            let request_url = self.webhooks_url("webhook")?;
+
+            let body = PostDataCenterWebhookBody {
+                name: webhook.name.as_deref().unwrap_or(url),
+                url,
+                events: &webhook.events,
+                filters: BTreeMap::from([(DC_JQL_FILTER_KEY, webhook.jql_filter.as_str())]),
+            };
+            let response = self.send(self.client.post(request_url).json(&body)).await?;
+            let body = deserialize_response::<DataCenterWebhook>(response).await?;
+            registered.push(RegisteredWebhook {
+                id: Some(Webhook::try_from(body)?.id),
+                errors: Vec::new(),
+            });
+        }
+        Ok(registered)
+    }
+
+    pub async fn delete_webhooks(&self, ids: &[i64]) -> Result<(), JiraClientError> {
+        if self.is_cloud() {
+            let url = self.api_url("webhook")?;
+
+            let body = serde_json::json!({ "webhookIds": ids });
+            self.send(self.client.delete(url).json(&body)).await?;
+            return Ok(());
+        }
+
+        for id in ids {
+            let url = self.webhooks_url(&format!("webhook/{}", id))?;
+
+            self.send(self.client.delete(url)).await?;
+        }
+        Ok(())
+    }
+}
+
+impl<D: CloudDeployment, A: Writable> JiraAPIClient<D, A> {
+    /// Webhooks expire after 30 days unless refreshed.
+    pub async fn refresh_webhooks(
+        &self,
+        ids: &[i64],
+    ) -> Result<PutWebhookRefreshResponseBody, JiraClientError> {
+        let url = self.api_url("webhook/refresh")?;
+
+        let body = PutWebhookRefreshBody {
+            webhook_ids: ids.to_vec(),
+        };
+        let response = self.send(self.client.put(url).json(&body)).await?;
+        let body = deserialize_response::<PutWebhookRefreshResponseBody>(response).await?;
+        Ok(body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn data_center_webhook_into_webhook() -> Result<(), JiraClientError> {
+        let webhook = serde_json::from_str::<DataCenterWebhook>(
+            r#"{
+                "self": "https://jira.example.com/rest/webhooks/1.0/webhook/12",
+                "name": "CI",
+                "url": "https://ci.example.com/jira",
+                "events": ["jira:issue_created"],
+                "filters": {"issue-related-events-section": "project = JB"},
+                "enabled": true
+            }"#,
+        )
+        .unwrap();
+
+        let webhook = Webhook::try_from(webhook)?;
+        assert_eq!(12, webhook.id);
+        assert_eq!(Some("project = JB".to_string()), webhook.jql_filter);
+        assert_eq!(Some("CI".to_string()), webhook.name);
+        Ok(())
+    }
+
+    #[test]
+    fn webhook_details_skip_data_center_name() -> Result<(), serde_json::Error> {
+        let webhook = WebhookDetails {
+            name: Some("CI".to_string()),
+            ..WebhookDetails::new("project = JB", &["jira:issue_created"])
+        };
+        assert_eq!(
+            serde_json::json!({"jqlFilter": "project = JB", "events": ["jira:issue_created"]}),
+            serde_json::to_value(&webhook)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn issue_updated_event() -> Result<(), serde_json::Error> {
+        let event = serde_json::from_str::<WebhookEvent>(
+            r#"{
+                "timestamp": 1704099600000,
+                "webhookEvent": "jira:issue_updated",
+                "issue_event_type_name": "issue_generic",
+                "issue": {
+                    "id": "10001",
+                    "key": "JB-1",
+                    "self": "https://jira.example.com/rest/api/2/issue/10001",
+                    "fields": {"summary": "Webhooks"}
+                },
+                "changelog": {
+                    "id": "10100",
+                    "items": [{
+                        "field": "summary",
+                        "fieldtype": "jira",
+                        "from": null,
+                        "fromString": "Hooks",
+                        "to": null,
+                        "toString": "Webhooks"
+                    }]
+                }
+            }"#,
+        )?;
+
+        let WebhookEvent::IssueUpdated(event) = event else {
+            panic!("Expected IssueUpdated, got {:?}", event);
+        };
+        assert_eq!("JB-1", event.issue.key.to_string());
+        assert_eq!(
+            Some("Webhooks".to_string()),
+            event.changelog.unwrap().items[0].to_string
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn worklog_created_event() -> Result<(), serde_json::Error> {
+        let event = serde_json::from_str::<WebhookEvent>(
+            r#"{
+                "timestamp": 1704099600000,
+                "webhookEvent": "worklog_created",
+                "worklog": {
+                    "self": "https://jira.example.com/rest/api/2/issue/10001/worklog/10200",
+                    "id": "10200",
+                    "issueId": "10001",
+                    "created": "2024-01-01T09:00:00.000+0000",
+                    "updated": "2024-01-01T09:00:00.000+0000",
+                    "started": "2024-01-01T09:00:00.000+0000",
+                    "timeSpent": "1h",
+                    "timeSpentSeconds": 3600
+                }
+            }"#,
+        )?;
+
+        let WebhookEvent::WorklogCreated(event) = event else {
+            panic!("Expected WorklogCreated, got {:?}", event);
+        };
+        assert_eq!(3600, event.worklog.time_spent_seconds);
+        Ok(())
+    }
+}